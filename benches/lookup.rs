@@ -0,0 +1,100 @@
+//! Lookup benchmarks across density regimes.
+//!
+//! Loads the database the build script wrote to `OUT_DIR`, or the file named by the
+//! `GENOM_BENCH_DATABASE` environment variable, so the benchmarks never download data.
+//! Without either, the lookup benchmarks fall back to the fixture database when the
+//! `test-fixtures` feature is enabled.
+//!
+//! Run with `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use genom::Geocoder;
+use std::hint::black_box;
+
+/// Representative queries: (name, latitude, longitude).
+const QUERIES: &[(&str, f64, f64)] = &[
+    ("dense_tokyo", 35.6762, 139.6503),
+    ("sparse_central_australia", -25.3444, 131.0369),
+    ("ocean", 0.0, -160.0),
+    ("high_latitude_svalbard", 78.2232, 15.6267),
+];
+
+fn database() -> Option<Vec<u8>> {
+    let path = std::env::var("GENOM_BENCH_DATABASE")
+        .unwrap_or_else(|_| concat!(env!("OUT_DIR"), "/places.bin").to_string());
+    match std::fs::read(&path) {
+        Ok(data) => Some(data),
+        Err(e) => {
+            eprintln!("skipping benchmarks: cannot read {}: {}", path, e);
+            None
+        }
+    }
+}
+
+fn geocoder() -> Option<Geocoder> {
+    if let Some(data) = database() {
+        return Some(Geocoder::from_bytes(&data).expect("invalid database"));
+    }
+    #[cfg(feature = "test-fixtures")]
+    return Some(genom::fixtures::geocoder());
+    #[cfg(not(feature = "test-fixtures"))]
+    None
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    let Some(geocoder) = geocoder() else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("lookup");
+    for &(name, lat, lon) in QUERIES {
+        group.bench_function(name, |b| {
+            b.iter(|| geocoder.lookup(black_box(lat), black_box(lon)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("lookup_ref");
+    for &(name, lat, lon) in QUERIES {
+        group.bench_function(name, |b| {
+            b.iter(|| geocoder.lookup_ref(black_box(lat), black_box(lon)))
+        });
+    }
+    group.finish();
+
+    // Builds the k-d tree outside the measured loop.
+    geocoder.lookup_exact(0.0, 0.0);
+    let mut group = c.benchmark_group("lookup_exact");
+    for &(name, lat, lon) in QUERIES {
+        group.bench_function(name, |b| {
+            b.iter(|| geocoder.lookup_exact(black_box(lat), black_box(lon)))
+        });
+    }
+    group.finish();
+
+    c.bench_function("lookup_batch", |b| {
+        let coords: Vec<(f64, f64)> = QUERIES.iter().map(|&(_, lat, lon)| (lat, lon)).collect();
+        b.iter(|| {
+            black_box(&coords)
+                .iter()
+                .map(|&(lat, lon)| geocoder.lookup(lat, lon))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+fn bench_init(c: &mut Criterion) {
+    let Some(data) = database() else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("init");
+    group.sample_size(10);
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| Geocoder::from_bytes(black_box(&data)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookup, bench_init);
+criterion_main!(benches);
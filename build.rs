@@ -45,7 +45,13 @@ fn main() {
             eprintln!("Database built successfully");
             println!("cargo:rerun-if-changed=build.rs");
             println!("cargo:rerun-if-changed=build/builder.rs");
+            println!("cargo:rerun-if-changed=build/countries.rs");
             println!("cargo:rerun-if-changed=build/types.rs");
+            println!("cargo:rerun-if-env-changed=GENOM_NAME_LANGUAGES");
+            println!("cargo:rerun-if-env-changed=GENOM_SKIP_POSTAL_CODES");
+            println!("cargo:rerun-if-env-changed=GENOM_BOUNDING_BOX");
+            println!("cargo:rerun-if-env-changed=GENOM_ALLOW_MISSING_COUNTRIES");
+            println!("cargo:rerun-if-env-changed=GENOM_MAX_CONCURRENCY");
         }
         Err(e) => {
             eprintln!("cargo:warning=Failed to build database: {}", e);
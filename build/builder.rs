@@ -4,23 +4,28 @@
 //!
 //! 1. **Download Phase**: Fetches data from GeoNames.org
 //!    - Administrative codes (admin1CodesASCII.txt, admin2Codes.txt)
-//!    - Alternate names for ISO codes (alternateNamesV2.zip)
+//!    - Alternate names for ISO codes and localized city names (alternateNamesV2.zip)
 //!    - Place data for each country (e.g., US.zip, FR.zip)
 //!    - Postal code data for each country
 //!
 //! 2. **Processing Phase**: Transforms raw data
 //!    - Filters places by feature codes (cities, towns, villages)
+//!    - Resolves admin3/admin4 subdivision names from `ADM3`/`ADM4` rows
 //!    - Merges postal codes with nearest places
 //!    - Deduplicates entries based on proximity
+//!    - Attaches localized names for the configured languages
 //!
 //! 3. **Optimization Phase**: Reduces memory footprint
-//!    - String interning to deduplicate common strings
+//!    - String interning to deduplicate common strings (index 0 is the empty string)
 //!    - Fixed-point coordinate encoding (5 decimal places)
 //!    - Spatial grid indexing for fast lookups
 //!
 //! 4. **Serialization Phase**: Writes binary database
 //!    - Uses varint encoding for compact binary format
+//!    - Stores places column-wise so coordinates are contiguous
+//!    - Stores the grid as sorted keys plus CSR offsets/indices
 //!    - Typical output size: 20-30 MB for 100+ countries
+//!    - With the `zstd` feature, the whole file is wrapped in a zstd frame
 //!
 //! # Data Sources
 //!
@@ -28,27 +33,30 @@
 //! which provides free geographic data under Creative Commons Attribution 4.0 license.
 
 use rustc_hash::FxHashMap;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Read, Write};
-use std::sync::{Arc, Mutex};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 use types::CompactPlace;
 
 use crate::types;
 
-/// Countries to include in the database.
+include!("countries.rs");
+
+/// Countries with fewer places than this are flagged in the coverage report.
 ///
-/// This list focuses on countries with significant population and data quality.
-/// Adding more countries increases build time and database size proportionally.
-const COUNTRIES: &[&str] = &[
-    "AD", "AE", "AI", "AL", "AR", "AS", "AT", "AU", "AX", "AZ", "BD", "BE", "BG", "BM", "BR", "BY",
-    "CA", "CC", "CH", "CL", "CN", "CO", "CR", "CX", "CY", "CZ", "DE", "DK", "DO", "DZ", "EC", "EE",
-    "ES", "FI", "FK", "FM", "FO", "FR", "GB", "GF", "GG", "GI", "GL", "GP", "GS", "GT", "GU", "HK",
-    "HM", "HN", "HR", "HT", "HU", "ID", "IE", "IM", "IN", "IO", "IS", "IT", "JE", "JP", "KE", "KR",
-    "LI", "LK", "LT", "LU", "LV", "MA", "MC", "MD", "MH", "MK", "MO", "MP", "MQ", "MT", "MW", "MX",
-    "MY", "NC", "NF", "NL", "NO", "NR", "NU", "NZ", "PA", "PE", "PF", "PH", "PK", "PL", "PM", "PN",
-    "PR", "PT", "PW", "RE", "RO", "RS", "RU", "SE", "SG", "SI", "SJ", "SK", "SM", "TC", "TH", "TR",
-    "UA", "US", "UY", "VA", "VI", "WF", "WS", "YT", "ZA",
-];
+/// Some territories (e.g., Heard Island, Pitcairn) legitimately have almost no populated
+/// places, so a low count is reported but does not fail the build.
+const MIN_EXPECTED_PLACES: usize = 10;
+
+/// Default number of countries downloaded at the same time.
+///
+/// One thread per country opens well over 200 simultaneous connections to
+/// geonames.org, which triggers rate limiting and timeouts.
+const DEFAULT_MAX_CONCURRENCY: usize = 8;
 
 /// GeoNames feature codes for populated places.
 ///
@@ -65,6 +73,62 @@ const FEATURE_CODES: &[&str] = &[
     "PPL", "PPLA", "PPLA2", "PPLA3", "PPLA4", "PPLC", "PPLG", "PPLS",
 ];
 
+/// Languages whose localized city names are stored in the database.
+///
+/// Can be overridden with a comma-separated list in the `GENOM_NAME_LANGUAGES`
+/// environment variable (e.g., `GENOM_NAME_LANGUAGES=de,fr`). Each language adds
+/// roughly one string table entry per place that has a distinct name in it.
+const NAME_LANGUAGES: &[&str] = &[
+    "ar", "de", "en", "es", "fr", "it", "ja", "ko", "pt", "ru", "zh",
+];
+
+/// Rectangle of coordinates restricting which places are built into the database.
+///
+/// A box whose western longitude is greater than its eastern one wraps around the
+/// antimeridian.
+#[derive(Debug, Clone, Copy)]
+struct BoundingBox {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl BoundingBox {
+    /// Parses `min_lat,min_lon,max_lat,max_lon` in decimal degrees.
+    fn parse(s: &str) -> Option<Self> {
+        let values: Vec<f64> = s
+            .split(',')
+            .map(|v| v.trim().parse().ok())
+            .collect::<Option<_>>()?;
+        let [min_lat, min_lon, max_lat, max_lon] = values[..] else {
+            return None;
+        };
+        let valid = (-90.0..=90.0).contains(&min_lat)
+            && (-90.0..=90.0).contains(&max_lat)
+            && min_lat <= max_lat
+            && (-180.0..=180.0).contains(&min_lon)
+            && (-180.0..=180.0).contains(&max_lon);
+        valid.then_some(Self {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        })
+    }
+
+    /// Returns `true` if the fixed-point coordinates lie inside the box.
+    fn contains(&self, lat: i32, lon: i32) -> bool {
+        let (lat, lon) = (lat as f64 / 100000.0, lon as f64 / 100000.0);
+        let lon_inside = if self.min_lon <= self.max_lon {
+            (self.min_lon..=self.max_lon).contains(&lon)
+        } else {
+            lon >= self.min_lon || lon <= self.max_lon
+        };
+        (self.min_lat..=self.max_lat).contains(&lat) && lon_inside
+    }
+}
+
 /// Temporary place structure used during database construction.
 ///
 /// This struct holds raw place data before string interning and final serialization.
@@ -72,24 +136,38 @@ const FEATURE_CODES: &[&str] = &[
 /// precision while using less memory than f64.
 #[derive(Debug)]
 struct TempPlace {
+    /// GeoNames ID of the place
+    geoname_id: u32,
     /// City or locality name
     city: String,
+    /// ASCII-only city name
+    city_ascii: String,
     /// State/province name
     region: String,
     /// ISO 3166-2 region code
     region_code: String,
     /// County/district name
     district: String,
+    /// Finer subdivision name from admin4 (or admin3 if absent)
+    subdistrict: String,
     /// ISO 3166-1 alpha-2 country code
     country_code: String,
     /// Postal/ZIP code
     postal_code: String,
     /// IANA timezone identifier
     timezone: String,
+    /// GeoNames feature code (one of [`FEATURE_CODES`])
+    feature_code: String,
+    /// Last modification date in days since 1970-01-01 (0 if unknown)
+    modified: u16,
+    /// Number of inhabitants (0 if unknown)
+    population: u32,
     /// Latitude as fixed-point integer (degrees * 100,000)
     lat: i32,
     /// Longitude as fixed-point integer (degrees * 100,000)
     lon: i32,
+    /// Localized names as (language, name) pairs
+    alternate_names: Vec<(String, String)>,
 }
 
 /// Database builder that orchestrates the entire construction process.
@@ -103,30 +181,117 @@ pub struct Builder {
     admin2: FxHashMap<String, String>,
     /// Maps GeoNames IDs to ISO region codes for admin1 divisions
     admin1_iso: FxHashMap<u32, String>,
+    /// Languages to collect localized names for
+    name_languages: Vec<String>,
+    /// Maps GeoNames IDs to localized (language, name) pairs
+    alternate_names: FxHashMap<u32, Vec<(String, String)>>,
+    /// Whether to download postal codes and assign them to places
+    postal_codes: bool,
+    /// Only places inside this box are built into the database, if set
+    bounding_box: Option<BoundingBox>,
+    /// Whether to build even if some country downloads fail
+    allow_missing_countries: bool,
+    /// Maximum number of countries downloaded at the same time
+    max_concurrency: usize,
 }
 
 impl Builder {
     /// Creates a new database builder with empty lookup tables.
+    ///
+    /// The localized name languages are read from `GENOM_NAME_LANGUAGES`, falling back
+    /// to [`NAME_LANGUAGES`]. Setting `GENOM_SKIP_POSTAL_CODES` to a non-empty value
+    /// other than `0` skips the postal code download and merge, leaving every
+    /// `postal_code` empty. Setting `GENOM_BOUNDING_BOX` to
+    /// `min_lat,min_lon,max_lat,max_lon` keeps only the places inside that rectangle.
+    /// Setting `GENOM_ALLOW_MISSING_COUNTRIES` to a non-empty value other than `0` lets
+    /// the build succeed when some country downloads fail. Setting
+    /// `GENOM_MAX_CONCURRENCY` overrides the number of parallel country downloads
+    /// (see [`Builder::with_max_concurrency`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `GENOM_BOUNDING_BOX` is set but is not four valid coordinates, rather
+    /// than silently building the whole world, or if `GENOM_MAX_CONCURRENCY` is set but
+    /// is not a number.
     pub fn new() -> Self {
-        Self {
+        let name_languages = match std::env::var("GENOM_NAME_LANGUAGES") {
+            Ok(list) => list
+                .split(',')
+                .map(|lang| lang.trim().to_string())
+                .filter(|lang| !lang.is_empty())
+                .collect(),
+            Err(_) => NAME_LANGUAGES.iter().map(|lang| lang.to_string()).collect(),
+        };
+        let postal_codes = std::env::var("GENOM_SKIP_POSTAL_CODES")
+            .map_or(true, |skip| skip.is_empty() || skip == "0");
+        let bounding_box = std::env::var("GENOM_BOUNDING_BOX")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| {
+                BoundingBox::parse(&value).unwrap_or_else(|| {
+                    panic!(
+                        "GENOM_BOUNDING_BOX must be min_lat,min_lon,max_lat,max_lon, got {:?}",
+                        value
+                    )
+                })
+            });
+
+        let allow_missing_countries = std::env::var("GENOM_ALLOW_MISSING_COUNTRIES")
+            .is_ok_and(|allow| !allow.is_empty() && allow != "0");
+        let max_concurrency = std::env::var("GENOM_MAX_CONCURRENCY")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| {
+                value.trim().parse().unwrap_or_else(|_| {
+                    panic!("GENOM_MAX_CONCURRENCY must be a number, got {:?}", value)
+                })
+            });
+
+        let builder = Self {
             admin1: FxHashMap::default(),
             admin2: FxHashMap::default(),
             admin1_iso: FxHashMap::default(),
+            name_languages,
+            alternate_names: FxHashMap::default(),
+            postal_codes,
+            bounding_box,
+            allow_missing_countries,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+        };
+        match max_concurrency {
+            Some(max_concurrency) => builder.with_max_concurrency(max_concurrency),
+            None => builder,
         }
     }
 
+    /// Limits how many countries are downloaded at the same time.
+    ///
+    /// Place and postal code downloads run on a pool of at most `max_concurrency`
+    /// threads, defaulting to [`DEFAULT_MAX_CONCURRENCY`]. Lower values are gentler on
+    /// geonames.org and less likely to hit rate limits; a value of 0 is treated as 1.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
     /// Builds the complete database and writes it to the specified path.
     ///
     /// # Process
     ///
     /// 1. Downloads administrative codes from GeoNames
-    /// 2. Downloads place data for all countries in parallel
-    /// 3. Downloads postal code data in parallel
-    /// 4. Merges postal codes with nearest places
+    /// 2. Downloads place data for all countries in parallel and reports countries
+    ///    whose download failed or produced few places
+    /// 3. Downloads postal code data in parallel (unless skipped)
+    /// 4. Merges postal codes with nearest places (unless skipped)
     /// 5. Deduplicates places within ~1km radius
-    /// 6. Interns strings to reduce memory usage
-    /// 7. Builds spatial grid index
-    /// 8. Serializes to binary format with varint encoding
+    /// 6. Attaches localized names
+    /// 7. Interns strings to reduce memory usage
+    /// 8. Builds spatial grid index
+    /// 9. Serializes to binary format with varint encoding, storing places column-wise
+    ///    (latitudes, longitudes, then one column per string field), the localized
+    ///    names as offsets plus (language, name) pairs, and the grid in CSR layout
+    ///    (sorted keys, offsets, concatenated indices)
+    /// 10. Compresses the output with zstd (`zstd` feature only)
     ///
     /// # Arguments
     ///
@@ -135,7 +300,8 @@ impl Builder {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Network requests fail
+    /// - Network requests fail, including a country download (unless
+    ///   `GENOM_ALLOW_MISSING_COUNTRIES` is set)
     /// - Downloaded data is malformed
     /// - File system operations fail
     /// - Serialization fails
@@ -145,25 +311,97 @@ impl Builder {
     /// Typical build time: 2-5 minutes depending on network speed.
     /// Uses parallel downloads to minimize wall-clock time.
     pub fn build(&mut self, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let places = self.collect_places()?;
+        println!("Building database for {} places...", places.len());
+        self.write_database(Path::new(output_path), places)
+    }
+
+    /// Builds one database per country and writes them to `output_dir`.
+    ///
+    /// Runs the same pipeline as [`Builder::build`], then splits the deduplicated places
+    /// by country and writes each group to `{CC}.bin`, a complete database with its own
+    /// string table and grid. A [`types::SHARD_INDEX`] file lists one `CC<TAB>places`
+    /// line per shard. Countries without places get no shard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Builder::build`], or if
+    /// `output_dir` cannot be created.
+    #[allow(dead_code)] // used by the build-database binary, not the build script
+    pub fn build_sharded(&mut self, output_dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let places = self.collect_places()?;
+        let output_dir = Path::new(output_dir);
+        std::fs::create_dir_all(output_dir)?;
+
+        let mut by_country: BTreeMap<String, Vec<TempPlace>> = BTreeMap::new();
+        for place in places {
+            by_country
+                .entry(place.country_code.clone())
+                .or_default()
+                .push(place);
+        }
+
+        let mut index = BufWriter::new(File::create(output_dir.join(types::SHARD_INDEX))?);
+        for (country, places) in by_country {
+            println!("Building {} shard for {} places...", country, places.len());
+            writeln!(index, "{}\t{}", country, places.len())?;
+            self.write_database(&output_dir.join(format!("{}.bin", country)), places)?;
+        }
+        index.flush()?;
+        Ok(())
+    }
+
+    /// Downloads, merges, deduplicates and names all places.
+    fn collect_places(&mut self) -> Result<Vec<TempPlace>, Box<dyn std::error::Error>> {
         println!("Downloading admin codes...");
         self.download_admin_codes()?;
-        self.download_admin_iso_codes()?;
+        self.download_alternate_names()?;
 
         println!("Downloading places...");
         let mut places = self.download_places()?;
 
-        println!("Downloading postal codes...");
-        self.merge_postal_codes(&mut places, self.download_postal_codes()?);
+        if self.postal_codes {
+            println!("Downloading postal codes...");
+            self.merge_postal_codes(&mut places, self.download_postal_codes()?);
+        } else {
+            println!("Skipping postal codes");
+        }
+
+        if let Some(bounding_box) = self.bounding_box {
+            places.retain(|place| bounding_box.contains(place.lat, place.lon));
+            println!("Kept {} places inside the bounding box", places.len());
+        }
 
         println!("Deduplicating {} places...", places.len());
-        let places = self.deduplicate_places(places);
+        let mut places = self.deduplicate_places(places);
+        self.attach_alternate_names(&mut places);
+        Ok(places)
+    }
 
-        println!("Building database for {} places...", places.len());
-        let (strings, compact_places) = self.intern_strings(places);
+    /// Interns, indexes and serializes `places` to a database file at `output_path`.
+    fn write_database(
+        &self,
+        output_path: &Path,
+        places: Vec<TempPlace>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (strings, compact_places, names) = self.intern_strings(places);
         let grid = self.build_grid(&compact_places);
 
-        println!("Writing database...");
-        let mut out = BufWriter::new(File::create(output_path)?);
+        println!("Writing {}...", output_path.display());
+        let out = BufWriter::new(File::create(output_path)?);
+        #[cfg(feature = "zstd")]
+        let mut out = zstd::Encoder::new(out, types::ZSTD_LEVEL)?;
+        #[cfg(not(feature = "zstd"))]
+        let mut out = out;
+
+        let built_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let data_date = compact_places.iter().map(|p| p.modified).max().unwrap_or(0);
+        out.write_all(&types::MAGIC)?;
+        out.write_all(&types::FORMAT_VERSION.to_le_bytes())?;
+        out.write_all(&built_at.to_le_bytes())?;
+        out.write_all(&data_date.to_le_bytes())?;
 
         out.write_all(&(strings.len() as u64).to_le_bytes())?;
         for s in &strings {
@@ -174,27 +412,68 @@ impl Builder {
 
         out.write_all(&(compact_places.len() as u64).to_le_bytes())?;
         for place in &compact_places {
-            out.write_all(&place.city.to_le_bytes())?;
-            out.write_all(&place.region.to_le_bytes())?;
-            out.write_all(&place.region_code.to_le_bytes())?;
-            out.write_all(&place.district.to_le_bytes())?;
-            out.write_all(&place.country_code.to_le_bytes())?;
-            out.write_all(&place.postal_code.to_le_bytes())?;
-            out.write_all(&place.timezone.to_le_bytes())?;
             out.write_all(&place.lat.to_le_bytes())?;
+        }
+        for place in &compact_places {
             out.write_all(&place.lon.to_le_bytes())?;
         }
+        let columns: [fn(&CompactPlace) -> u32; 10] = [
+            |p| p.city,
+            |p| p.city_ascii,
+            |p| p.region,
+            |p| p.region_code,
+            |p| p.district,
+            |p| p.subdistrict,
+            |p| p.country_code,
+            |p| p.postal_code,
+            |p| p.timezone,
+            |p| p.feature_code,
+        ];
+        for column in columns {
+            for place in &compact_places {
+                out.write_all(&column(place).to_le_bytes())?;
+            }
+        }
+        for place in &compact_places {
+            out.write_all(&place.modified.to_le_bytes())?;
+        }
+        for place in &compact_places {
+            out.write_all(&place.population.to_le_bytes())?;
+        }
 
-        out.write_all(&(grid.len() as u64).to_le_bytes())?;
-        for ((lat, lon), indices) in &grid {
+        let mut offset = 0u32;
+        out.write_all(&offset.to_le_bytes())?;
+        for place_names in &names {
+            offset += place_names.len() as u32;
+            out.write_all(&offset.to_le_bytes())?;
+        }
+        for (lang, name) in names.iter().flatten() {
+            out.write_all(&lang.to_le_bytes())?;
+            out.write_all(&name.to_le_bytes())?;
+        }
+
+        let mut cells: Vec<_> = grid.iter().collect();
+        cells.sort_unstable_by_key(|(key, _)| **key);
+
+        out.write_all(&(cells.len() as u64).to_le_bytes())?;
+        for ((lat, lon), _) in &cells {
             out.write_all(&lat.to_le_bytes())?;
             out.write_all(&lon.to_le_bytes())?;
-            out.write_all(&(indices.len() as u64).to_le_bytes())?;
-            for idx in indices {
+        }
+        let mut offset = 0u32;
+        out.write_all(&offset.to_le_bytes())?;
+        for (_, indices) in &cells {
+            offset += indices.len() as u32;
+            out.write_all(&offset.to_le_bytes())?;
+        }
+        for (_, indices) in &cells {
+            for idx in indices.iter() {
                 out.write_all(&idx.to_le_bytes())?;
             }
         }
 
+        #[cfg(feature = "zstd")]
+        let mut out = out.finish()?;
         out.flush()?;
         let size = std::fs::metadata(output_path)?.len();
         println!("Done! Database size: {} MB", size / 1_000_000);
@@ -212,11 +491,13 @@ impl Builder {
         Ok(())
     }
 
-    /// Downloads ISO region codes from alternate names database.
+    /// Downloads ISO region codes and localized names from the alternate names database.
     ///
     /// Maps GeoNames admin1 IDs to their ISO 3166-2 region codes
-    /// (e.g., "CA" for California instead of just the numeric code).
-    fn download_admin_iso_codes(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    /// (e.g., "CA" for California instead of just the numeric code), and collects one
+    /// name per configured language for every GeoNames ID. Preferred names win over
+    /// other names; colloquial and historic names are skipped.
+    fn download_alternate_names(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let url = "https://download.geonames.org/export/dump/alternateNamesV2.zip";
         let bytes = reqwest::blocking::get(url)?.bytes()?;
         let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))?;
@@ -227,9 +508,25 @@ impl Builder {
 
         for line in content.lines() {
             let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() >= 4 && parts[2] == "abbr" {
-                if let Ok(id) = parts[1].parse::<u32>() {
-                    self.admin1_iso.insert(id, parts[3].to_string());
+            if parts.len() < 4 {
+                continue;
+            }
+            let Ok(id) = parts[1].parse::<u32>() else {
+                continue;
+            };
+
+            if parts[2] == "abbr" {
+                self.admin1_iso.insert(id, parts[3].to_string());
+            } else if self.name_languages.iter().any(|lang| lang == parts[2]) {
+                let flag = |i: usize| parts.get(i) == Some(&"1");
+                if flag(6) || flag(7) {
+                    continue;
+                }
+                let names = self.alternate_names.entry(id).or_default();
+                match names.iter_mut().find(|(lang, _)| lang == parts[2]) {
+                    Some(entry) if flag(4) => entry.1 = parts[3].to_string(),
+                    Some(_) => {}
+                    None => names.push((parts[2].to_string(), parts[3].to_string())),
                 }
             }
         }
@@ -264,32 +561,75 @@ impl Builder {
     /// # Returns
     ///
     /// A vector of all places from all countries combined.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any country failed to download, unless missing countries
+    /// are allowed. See [`Builder::report_coverage`].
     fn download_places(&self) -> Result<Vec<TempPlace>, Box<dyn std::error::Error>> {
-        let places = Arc::new(Mutex::new(Vec::new()));
-        let (admin1, admin2, admin1_iso) = (
-            Arc::new(self.admin1.clone()),
-            Arc::new(self.admin2.clone()),
-            Arc::new(self.admin1_iso.clone()),
-        );
+        let places = Mutex::new(Vec::new());
+        let coverage = Mutex::new(Vec::new());
 
-        std::thread::scope(|scope| {
-            for country in COUNTRIES {
-                let (places, admin1, admin2, admin1_iso) = (
-                    Arc::clone(&places),
-                    Arc::clone(&admin1),
-                    Arc::clone(&admin2),
-                    Arc::clone(&admin1_iso),
-                );
+        for_each_country(self.max_concurrency, |country| {
+            let result = download_country(country, &self.admin1, &self.admin2, &self.admin1_iso);
+            let count = match result {
+                Ok(data) => {
+                    let count = data.len();
+                    places.lock().unwrap().extend(data);
+                    Ok(count)
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            coverage.lock().unwrap().push((country, count));
+        });
+
+        let mut coverage = coverage.into_inner().unwrap();
+        coverage.sort_unstable_by_key(|(country, _)| *country);
+        self.report_coverage(&coverage)?;
 
-                scope.spawn(move || {
-                    if let Ok(data) = download_country(country, &admin1, &admin2, &admin1_iso) {
-                        places.lock().unwrap().extend(data);
+        Ok(places.into_inner().unwrap())
+    }
+
+    /// Prints per-country download results, flagging failed and sparse countries.
+    ///
+    /// Countries with fewer than [`MIN_EXPECTED_PLACES`] places are listed as a warning.
+    /// A failed download would otherwise silently leave a country out of the database,
+    /// so it fails the build unless `GENOM_ALLOW_MISSING_COUNTRIES` is set.
+    fn report_coverage(
+        &self,
+        coverage: &[(&str, Result<usize, String>)],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut failed = Vec::new();
+        let mut total = 0;
+        for (country, result) in coverage {
+            match result {
+                Ok(count) => {
+                    total += count;
+                    if *count < MIN_EXPECTED_PLACES {
+                        println!("  {}: only {} places", country, count);
                     }
-                });
+                }
+                Err(e) => {
+                    println!("  {}: download failed: {}", country, e);
+                    failed.push(*country);
+                }
             }
-        });
+        }
+        println!(
+            "Downloaded {} places from {} of {} countries",
+            total,
+            coverage.len() - failed.len(),
+            coverage.len()
+        );
 
-        Ok(Arc::try_unwrap(places).unwrap().into_inner().unwrap())
+        if failed.is_empty() || self.allow_missing_countries {
+            return Ok(());
+        }
+        Err(format!(
+            "no places for {} (set GENOM_ALLOW_MISSING_COUNTRIES=1 to build without them)",
+            failed.join(", ")
+        )
+        .into())
     }
 
     /// Deduplicates places that are very close to each other.
@@ -300,14 +640,21 @@ impl Builder {
     /// 2. Sorts by postal code presence (places with postal codes preferred)
     /// 3. Keeps only one place per ~1km grid cell (lat/lon rounded to 3 decimals)
     ///
+    /// Remaining ties are broken by GeoNames ID. Countries are downloaded concurrently
+    /// and arrive in any order, so this keeps the surviving places and their database
+    /// indices identical across builds of the same data.
+    ///
     /// This removes duplicate entries for the same location while keeping
     /// the most complete data.
     fn deduplicate_places(&self, mut places: Vec<TempPlace>) -> Vec<TempPlace> {
+        // Capitals and administrative seats win over plain populated places, so that
+        // queries by feature code still find them.
         places.sort_by(|a, b| {
-            b.city
-                .len()
-                .cmp(&a.city.len())
+            (a.feature_code == "PPL")
+                .cmp(&(b.feature_code == "PPL"))
+                .then_with(|| b.city.len().cmp(&a.city.len()))
                 .then_with(|| a.postal_code.is_empty().cmp(&b.postal_code.is_empty()))
+                .then_with(|| a.geoname_id.cmp(&b.geoname_id))
         });
 
         let mut seen = FxHashMap::default();
@@ -315,6 +662,22 @@ impl Builder {
         places
     }
 
+    /// Moves the collected localized names onto the places they belong to.
+    ///
+    /// Names identical to the place's own name are dropped, since they add nothing
+    /// over the `city` field.
+    fn attach_alternate_names(&mut self, places: &mut [TempPlace]) {
+        for place in places {
+            if let Some(names) = self.alternate_names.remove(&place.geoname_id) {
+                place.alternate_names = names
+                    .into_iter()
+                    .filter(|(_, name)| *name != place.city)
+                    .collect();
+            }
+        }
+        self.alternate_names = FxHashMap::default();
+    }
+
     /// Converts places to compact format using string interning.
     ///
     /// # String Interning
@@ -326,30 +689,50 @@ impl Builder {
     ///
     /// # Returns
     ///
-    /// A tuple of (string_table, compact_places) where compact_places reference
-    /// strings by index.
-    fn intern_strings(&self, places: Vec<TempPlace>) -> (Vec<String>, Vec<CompactPlace>) {
+    /// A tuple of (string_table, compact_places, names) where compact_places and the
+    /// per-place (language, name) pairs reference strings by index.
+    #[allow(clippy::type_complexity)]
+    fn intern_strings(
+        &self,
+        places: Vec<TempPlace>,
+    ) -> (Vec<String>, Vec<CompactPlace>, Vec<Vec<(u32, u32)>>) {
         let mut string_map: FxHashMap<String, u32> = FxHashMap::default();
         let mut strings = Vec::new();
 
         let mut intern = |s: &str| intern_string(s, &mut string_map, &mut strings);
+        // Index 0 is always the empty string, see `Database::EMPTY_STRING`
+        intern("");
 
+        let mut names = Vec::with_capacity(places.len());
         let compact_places = places
             .into_iter()
-            .map(|p| CompactPlace {
-                city: intern(&p.city),
-                region: intern(&p.region),
-                region_code: intern(&p.region_code),
-                district: intern(&p.district),
-                country_code: intern(&p.country_code),
-                postal_code: intern(&p.postal_code),
-                timezone: intern(&p.timezone),
-                lat: p.lat,
-                lon: p.lon,
+            .map(|p| {
+                names.push(
+                    p.alternate_names
+                        .iter()
+                        .map(|(lang, name)| (intern(lang), intern(name)))
+                        .collect(),
+                );
+                CompactPlace {
+                    city: intern(&p.city),
+                    city_ascii: intern(&p.city_ascii),
+                    region: intern(&p.region),
+                    region_code: intern(&p.region_code),
+                    district: intern(&p.district),
+                    subdistrict: intern(&p.subdistrict),
+                    country_code: intern(&p.country_code),
+                    postal_code: intern(&p.postal_code),
+                    timezone: intern(&p.timezone),
+                    feature_code: intern(&p.feature_code),
+                    modified: p.modified,
+                    population: p.population,
+                    lat: p.lat,
+                    lon: p.lon,
+                }
             })
             .collect();
 
-        (strings, compact_places)
+        (strings, compact_places, names)
     }
 
     /// Builds a spatial grid index for fast coordinate lookups.
@@ -379,7 +762,7 @@ impl Builder {
     }
 }
 
-fn write_varint(out: &mut BufWriter<File>, mut value: u64) -> std::io::Result<()> {
+fn write_varint(out: &mut impl Write, mut value: u64) -> std::io::Result<()> {
     loop {
         let mut byte = (value & 0x7F) as u8;
         value >>= 7;
@@ -402,6 +785,47 @@ fn intern_string(s: &str, map: &mut FxHashMap<String, u32>, strings: &mut Vec<St
     })
 }
 
+/// Parses a GeoNames `YYYY-MM-DD` modification date into days since 1970-01-01.
+///
+/// Returns 0 for malformed dates and dates outside the `u16` day range (1970-2149).
+fn parse_modification_date(date: &str) -> u16 {
+    let mut parts = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (Some(Some(year)), Some(Some(month)), Some(Some(day))) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return 0;
+    };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return 0;
+    }
+
+    // Days from civil date, counting years from March so the leap day comes last.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    u16::try_from(days).unwrap_or(0)
+}
+
+/// Runs `task` for every country in [`COUNTRIES`] on at most `max_concurrency` threads.
+///
+/// Each worker thread takes the next country from a shared counter until all countries
+/// are done, so a slow download only holds up its own worker.
+fn for_each_country(max_concurrency: usize, task: impl Fn(&'static str) + Sync) {
+    let next = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..max_concurrency.min(COUNTRIES.len()) {
+            scope.spawn(|| {
+                while let Some(country) = COUNTRIES.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    task(country);
+                }
+            });
+        }
+    });
+}
+
 /// Downloads and parses place data for a single country.
 ///
 /// # Arguments
@@ -428,6 +852,8 @@ fn download_country(
         .by_name(&format!("{}.txt", country))?
         .read_to_string(&mut content)?;
 
+    let (admin3, admin4) = collect_subdivisions(country, &content);
+
     let places = content
         .lines()
         .filter_map(|line| {
@@ -446,6 +872,12 @@ fn download_country(
                 .get(&format!("{}.{}.{}", country, admin1_code, parts[11]))
                 .map(|s| s.as_str())
                 .unwrap_or("");
+            let admin3_key = format!("{}.{}.{}.{}", country, admin1_code, parts[11], parts[12]);
+            let subdistrict = admin4
+                .get(&format!("{}.{}", admin3_key, parts[13]))
+                .or_else(|| admin3.get(&admin3_key))
+                .map(|s| s.as_str())
+                .unwrap_or("");
 
             let region_code = if admin1_code == "00" || admin1_code.is_empty() {
                 String::new()
@@ -459,15 +891,24 @@ fn download_country(
             };
 
             Some(TempPlace {
-                city: parts[2].to_string(),
+                geoname_id: parts[0].parse().ok()?,
+                city: parts[1].to_string(),
+                city_ascii: parts[2].to_string(),
                 region: region.to_string(),
                 region_code,
                 district: district.to_string(),
+                subdistrict: subdistrict.to_string(),
                 country_code: country.to_string(),
                 postal_code: String::new(),
                 timezone: parts.get(17).unwrap_or(&"").to_string(),
+                feature_code: parts[7].to_string(),
+                modified: parts
+                    .get(18)
+                    .map_or(0, |date| parse_modification_date(date)),
+                population: parts[14].parse().unwrap_or(0),
                 lat: (lat * 100000.0) as i32,
                 lon: (lon * 100000.0) as i32,
+                alternate_names: Vec::new(),
             })
         })
         .collect();
@@ -475,6 +916,43 @@ fn download_country(
     Ok(places)
 }
 
+/// Collects third- and fourth-order administrative division names for a country.
+///
+/// GeoNames publishes admin1/admin2 names as separate code files, but admin3/admin4
+/// names only exist as `ADM3`/`ADM4` feature rows in the country file itself. This
+/// scans those rows and keys them like the admin code files:
+/// `CC.admin1.admin2.admin3` and `CC.admin1.admin2.admin3.admin4`.
+///
+/// # Returns
+///
+/// A tuple of (admin3, admin4) name lookup tables.
+fn collect_subdivisions(
+    country: &str,
+    content: &str,
+) -> (FxHashMap<String, String>, FxHashMap<String, String>) {
+    let mut admin3 = FxHashMap::default();
+    let mut admin4 = FxHashMap::default();
+
+    for line in content.lines() {
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() < 14 {
+            continue;
+        }
+        let key = format!("{}.{}.{}.{}", country, parts[10], parts[11], parts[12]);
+        match parts[7] {
+            "ADM3" => {
+                admin3.insert(key, parts[1].to_string());
+            }
+            "ADM4" => {
+                admin4.insert(format!("{}.{}", key, parts[13]), parts[1].to_string());
+            }
+            _ => {}
+        }
+    }
+
+    (admin3, admin4)
+}
+
 /// Postal code data structure used during database construction.
 #[derive(Debug)]
 struct PostalCode {
@@ -496,20 +974,15 @@ impl Builder {
     /// Postal codes provide more precise location data and district names
     /// that may be missing from the main place database.
     fn download_postal_codes(&self) -> Result<Vec<PostalCode>, Box<dyn std::error::Error>> {
-        let codes = Arc::new(Mutex::new(Vec::new()));
-
-        std::thread::scope(|scope| {
-            for country in COUNTRIES {
-                let codes = Arc::clone(&codes);
-                scope.spawn(move || {
-                    if let Ok(data) = download_postal_codes_for_country(country) {
-                        codes.lock().unwrap().extend(data);
-                    }
-                });
+        let codes = Mutex::new(Vec::new());
+
+        for_each_country(self.max_concurrency, |country| {
+            if let Ok(data) = download_postal_codes_for_country(country) {
+                codes.lock().unwrap().extend(data);
             }
         });
 
-        Ok(Arc::try_unwrap(codes).unwrap().into_inner().unwrap())
+        Ok(codes.into_inner().unwrap())
     }
 
     /// Merges postal code data with places by finding nearest postal code.
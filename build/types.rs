@@ -9,6 +9,21 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Magic bytes at the start of every database file (mirrors `Database::MAGIC`).
+pub const MAGIC: [u8; 4] = *b"GNOM";
+
+/// Version of the binary layout (mirrors `Database::FORMAT_VERSION`).
+pub const FORMAT_VERSION: u32 = 2;
+
+/// zstd compression level used for database files when the `zstd` feature is enabled.
+#[cfg(feature = "zstd")]
+pub const ZSTD_LEVEL: i32 = 19;
+
+/// Name of the index file written next to per-country shards (mirrors the runtime's
+/// shard index name).
+#[allow(dead_code)] // used by the build-database binary, not the build script
+pub const SHARD_INDEX: &str = "index.tsv";
+
 /// Enriched place data structure (build-time version).
 ///
 /// This mirrors the `Place` struct in the main crate but is used only
@@ -18,9 +33,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct Place {
     pub city: String,
+    pub city_ascii: String,
     pub region: String,
     pub region_code: String,
     pub district: String,
+    pub subdistrict: String,
     pub country_code: String,
     pub country_name: String,
     pub postal_code: String,
@@ -84,18 +101,28 @@ impl Location {
 pub struct CompactPlace {
     /// Index into string table for city name
     pub city: u32,
+    /// Index into string table for ASCII city name
+    pub city_ascii: u32,
     /// Index into string table for region name
     pub region: u32,
     /// Index into string table for region code
     pub region_code: u32,
     /// Index into string table for district name
     pub district: u32,
+    /// Index into string table for subdistrict name
+    pub subdistrict: u32,
     /// Index into string table for country code
     pub country_code: u32,
     /// Index into string table for postal code
     pub postal_code: u32,
     /// Index into string table for timezone
     pub timezone: u32,
+    /// Index into string table for GeoNames feature code
+    pub feature_code: u32,
+    /// Last modification date in days since 1970-01-01 (0 if unknown)
+    pub modified: u16,
+    /// Number of inhabitants (0 if unknown)
+    pub population: u32,
     /// Latitude as fixed-point integer (degrees * 100,000)
     pub lat: i32,
     /// Longitude as fixed-point integer (degrees * 100,000)
@@ -18,8 +18,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     {
         use builder::Builder;
 
-        let output_path = std::env::args()
-            .nth(1)
+        let args: Vec<String> = std::env::args().skip(1).collect();
+
+        if args.first().map(String::as_str) == Some("--shards") {
+            let output_dir = args.get(1).map_or("shards", String::as_str);
+            println!("Building per-country shards to: {}", output_dir);
+            Builder::new().build_sharded(output_dir)?;
+            println!("Shards built successfully!");
+            return Ok(());
+        }
+
+        let output_path = args
+            .first()
+            .cloned()
             .unwrap_or_else(|| "places.bin".to_string());
 
         println!("Building database to: {}", output_path);
@@ -0,0 +1,148 @@
+//! Cached geocoding for workloads with strong spatial locality.
+//!
+//! This module provides [`CachedGeocoder`], a wrapper around [`Geocoder`] that memoizes
+//! lookup results per grid cell in a least-recently-used cache.
+//!
+//! Requires the `cache` feature.
+
+#![warn(missing_docs)]
+
+use crate::database::Geocoder;
+use crate::types::{Location, Place};
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// A geocoder wrapper that caches results per grid cell.
+///
+/// The cache key is the quantized 0.1° grid cell of the query, not the exact
+/// coordinates, so "close enough" queries share one entry and skip the nearest-neighbor
+/// search and enrichment entirely. Cells without any place (e.g., open ocean) are cached
+/// as misses too.
+///
+/// # Approximation
+///
+/// Every query in a cell returns the place that was nearest to the *first* query that
+/// populated the entry. Within a ~11km cell the true nearest place can differ, so only
+/// use this wrapper when cell-level precision is acceptable.
+///
+/// Cached places keep the timezone fields (`utc_offset`, `dst_active`, ...) computed
+/// when they were first looked up and may become stale across a DST transition.
+///
+/// # Thread Safety
+///
+/// `CachedGeocoder` is `Sync`: the cache is guarded by a mutex and statistics use
+/// atomic counters, so a single instance can be shared between threads.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// use genom::CachedGeocoder;
+///
+/// let geocoder = CachedGeocoder::new(1024);
+///
+/// geocoder.lookup(48.8566, 2.3522); // miss
+/// geocoder.lookup(48.8567, 2.3523); // hit: same grid cell
+///
+/// let stats = geocoder.cache_stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+/// # }
+/// ```
+pub struct CachedGeocoder<'a> {
+    geocoder: &'a Geocoder,
+    cache: Mutex<LruCache<(i16, i16), Option<Place>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Hit and miss counters of a [`CachedGeocoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups answered from the cache
+    pub hits: u64,
+    /// Number of lookups that required a search
+    pub misses: u64,
+    /// Number of grid cells currently cached
+    pub len: usize,
+    /// Maximum number of grid cells the cache holds
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Returns the fraction of lookups answered from the cache, or 0.0 if no lookups
+    /// have been made.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+impl CachedGeocoder<'static> {
+    /// Creates a cache over the global geocoder holding up to `capacity` grid cells.
+    ///
+    /// A capacity of zero is treated as one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global database fails to initialize, like [`Geocoder::global()`].
+    pub fn new(capacity: usize) -> Self {
+        Self::with_geocoder(Geocoder::global(), capacity)
+    }
+}
+
+impl<'a> CachedGeocoder<'a> {
+    /// Creates a cache over the given geocoder holding up to `capacity` grid cells.
+    ///
+    /// A capacity of zero is treated as one.
+    pub fn with_geocoder(geocoder: &'a Geocoder, capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            geocoder,
+            cache: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Finds the place for the given coordinates, consulting the cache first.
+    ///
+    /// See the [type-level documentation](CachedGeocoder) for the approximation this
+    /// implies.
+    pub fn lookup(&self, latitude: f64, longitude: f64) -> Option<Place> {
+        let key = self.geocoder.grid_key(&Location::new(latitude, longitude));
+
+        if let Some(place) = self.cache.lock().unwrap().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return place.clone();
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let place = self.geocoder.lookup(latitude, longitude);
+        self.cache.lock().unwrap().put(key, place.clone());
+        place
+    }
+
+    /// Returns the current hit/miss counters and cache occupancy.
+    pub fn cache_stats(&self) -> CacheStats {
+        let cache = self.cache.lock().unwrap();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: cache.len(),
+            capacity: cache.cap().get(),
+        }
+    }
+
+    /// Removes all cached entries and resets the statistics.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
@@ -5,11 +5,28 @@
 
 #![warn(missing_docs)]
 
-use crate::enrichment::{enrich_place, PlaceInput};
-use crate::types::{Database, Location, Place};
+use crate::enrichment::{enrich_place, enrich_place_ref, PlaceInput};
+use crate::error::DatabaseError;
+use crate::kdtree::KdTree;
+use crate::types::{
+    AlternateNames, Database, DatabaseDiff, DatabaseInfo, DatabaseStats, DistanceMetric,
+    Ellipsoidal, Equirectangular, Grid, Haversine, Location, LookupDebug, LookupOptions, Place,
+    PlaceRef,
+};
+#[cfg(feature = "water")]
+use crate::types::{LookupResult, WaterBody};
+use rustc_hash::FxHashMap;
+use std::io::Read;
 use std::sync::OnceLock;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
-static GEOCODER: OnceLock<Geocoder> = OnceLock::new();
+static GEOCODER: OnceLock<Result<Geocoder, DatabaseError>> = OnceLock::new();
+
+/// Geocoder installed by [`reload_from_bytes()`](crate::reload_from_bytes), which takes
+/// precedence over [`GEOCODER`] for the crate-level lookup functions.
+#[cfg(feature = "reload")]
+static RELOADED: arc_swap::ArcSwapOption<Geocoder> = arc_swap::ArcSwapOption::const_empty();
 
 #[cfg(not(any(doc, clippy, feature = "no-build-database")))]
 static DATA: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/places.bin"));
@@ -17,6 +34,12 @@ static DATA: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/places.bin"));
 #[cfg(any(doc, clippy, feature = "no-build-database"))]
 static DATA: &[u8] = &[];
 
+/// Number of values [`Geocoder::read_column()`] decodes per read.
+const COLUMN_CHUNK: usize = 8192;
+
+/// Upper bound on the elements reserved up front from a length read out of the data.
+const MAX_PREALLOCATED: usize = 1 << 20;
+
 /// The core geocoding engine. Manages the spatial database and performs coordinate lookups.
 ///
 /// # Conceptual Role
@@ -24,7 +47,7 @@ static DATA: &[u8] = &[];
 /// `Geocoder` is the transport layer for all geographic queries. It handles:
 ///
 /// - Database initialization and decompression
-/// - Grid-based spatial indexing for O(1) lookups
+/// - Grid-based spatial indexing with binary-searched cells
 /// - Nearest-neighbor search across grid cells
 /// - String table resolution for compact storage
 ///
@@ -38,7 +61,7 @@ static DATA: &[u8] = &[];
 ///
 /// - After construction, the database is fully loaded and valid
 /// - Grid keys are consistent with coordinate quantization
-/// - String indices in [`CompactPlace`](crate::types::CompactPlace) are valid into strings vector
+/// - String indices in every column of [`Database`] are valid into the strings vector
 ///
 /// # Thread Safety
 ///
@@ -47,6 +70,18 @@ static DATA: &[u8] = &[];
 /// because all operations are read-only after initialization.
 pub struct Geocoder {
     db: Database,
+    countries: OnceLock<Vec<String>>,
+    timezones: OnceLock<Vec<String>>,
+    coverage: OnceLock<(f64, f64, f64, f64)>,
+    postal_index: OnceLock<FxHashMap<String, Vec<u32>>>,
+    name_index: OnceLock<Vec<(String, u32)>>,
+    kd_tree: OnceLock<KdTree>,
+    metric: Box<dyn DistanceMetric>,
+    /// Whether ring searches may stop early, which requires a metric in great-circle km
+    early_stop: bool,
+    /// Whether dense cells may be ranked by the vectorized equirectangular scan
+    #[cfg(feature = "simd")]
+    vectorize: bool,
 }
 
 impl Geocoder {
@@ -73,100 +108,599 @@ impl Geocoder {
     /// # }
     /// ```
     pub fn global() -> &'static Self {
-        GEOCODER.get_or_init(|| Self::new().expect("Failed to initialize geocoder"))
+        Self::try_global().unwrap_or_else(|e| panic!("Failed to initialize geocoder: {}", e))
+    }
+
+    /// Returns a reference to the global geocoder singleton, or the error that prevented
+    /// its initialization.
+    ///
+    /// This is the non-panicking counterpart of [`Geocoder::global()`]. The outcome of
+    /// the first initialization attempt is cached, so a corrupted database reports the
+    /// same error on every call without being decoded again. Built with the
+    /// `no-build-database` feature, there is no embedded database and this returns
+    /// [`DatabaseError::EmptyDatabase`]; load one with [`Geocoder::from_path()`] instead.
+    /// The same error is returned if the embedded database contains no places, and a
+    /// truncated one fails with [`DatabaseError::Truncated`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// match Geocoder::try_global() {
+    ///     Ok(geocoder) => println!("{:?}", geocoder.lookup(51.5074, -0.1278)),
+    ///     Err(e) => eprintln!("Database unavailable: {}", e),
+    /// }
+    /// # }
+    /// ```
+    pub fn try_global() -> Result<&'static Self, DatabaseError> {
+        GEOCODER
+            .get_or_init(Self::new)
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Runs `f` on the geocoder behind the crate-level functions such as
+    /// [`lookup()`](crate::lookup): the most recently reloaded one if any, otherwise the
+    /// global one.
+    ///
+    /// A reload during `f` does not affect it; `f` keeps using the geocoder it started with.
+    pub(crate) fn try_with_current<R>(f: impl FnOnce(&Geocoder) -> R) -> Result<R, DatabaseError> {
+        #[cfg(feature = "reload")]
+        if let Some(geocoder) = RELOADED.load().as_deref() {
+            return Ok(f(geocoder));
+        }
+        Self::try_global().map(f)
     }
 
-    fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let db = Self::load_database(DATA)?;
-        Ok(Self { db })
+    /// Like [`Geocoder::try_with_current()`], but panics like [`Geocoder::global()`] if
+    /// the database cannot be initialized.
+    pub(crate) fn with_current<R>(f: impl FnOnce(&Geocoder) -> R) -> R {
+        Self::try_with_current(f).unwrap_or_else(|e| panic!("Failed to initialize geocoder: {}", e))
     }
 
-    fn load_database(data: &[u8]) -> Result<Database, Box<dyn std::error::Error>> {
-        let mut cursor = std::io::Cursor::new(data);
-        use std::io::Read;
+    /// Replaces the geocoder used by the crate-level functions.
+    #[cfg(feature = "reload")]
+    pub(crate) fn replace_current(geocoder: Geocoder) {
+        RELOADED.store(Some(std::sync::Arc::new(geocoder)));
+    }
 
-        let mut buf8 = [0u8; 8];
-        let mut buf4 = [0u8; 4];
+    /// Decodes a geocoder from a binary database in the format written by the builder.
+    ///
+    /// Unlike [`Geocoder::global()`], this does not touch the embedded database or any
+    /// global state: the caller owns the returned instance. This is useful when the
+    /// database lives outside the binary (e.g., in a file or flash-resident memory
+    /// region), or when the crate is built with the `no-build-database` feature.
+    ///
+    /// The codec is detected from the leading bytes, so the data may be a plain
+    /// database or one compressed with zstd (`zstd` feature) or xz (`xz` feature).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::UnsupportedCompression`] if the data is compressed and
+    /// the codec's feature is disabled, and another [`DatabaseError`] if the data is
+    /// truncated or malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use genom::Geocoder;
+    ///
+    /// let data = std::fs::read("places.bin")?;
+    /// let geocoder = Geocoder::from_bytes(&data)?;
+    /// let place = geocoder.lookup(48.8566, 2.3522);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Result<Self, DatabaseError> {
+        Self::from_reader(data)
+    }
+
+    /// Loads a geocoder by streaming a database out of a reader.
+    ///
+    /// The data is decoded section by section as it is read - string table, place
+    /// columns, then the grid - so the serialized bytes never need to be resident in
+    /// full. Peak memory during init stays close to the size of the decoded database
+    /// instead of that plus a copy of the file, which matters on small devices where
+    /// the init-time spike is the limiting factor.
+    ///
+    /// The reader is consumed in many small reads; wrap files and sockets in a
+    /// [`BufReader`](std::io::BufReader). A database compressed with zstd (`zstd`
+    /// feature) or xz (`xz` feature) is recognized by its leading bytes and decompressed
+    /// on the fly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Io`] if the reader fails,
+    /// [`DatabaseError::UnsupportedCompression`] if the data is compressed and the
+    /// codec's feature is disabled, and a decoding error if the data is truncated or
+    /// malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use genom::Geocoder;
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// let geocoder = Geocoder::from_reader(BufReader::new(File::open("places.bin")?))?;
+    /// let place = geocoder.lookup(48.8566, 2.3522);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, DatabaseError> {
+        Ok(Self::from_database(Self::load_database(reader)?))
+    }
+
+    /// Loads a geocoder from a database file.
+    ///
+    /// Streams the file through [`Geocoder::from_reader()`] with a buffered reader. This
+    /// is the usual way to supply the database when the crate is built with the
+    /// `no-build-database` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Io`] if the file cannot be opened or read, and the
+    /// errors of [`Geocoder::from_reader()`] if its contents cannot be decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::from_path("places.bin")?;
+    /// let place = geocoder.lookup(48.8566, 2.3522);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, DatabaseError> {
+        let file = std::fs::File::open(path).map_err(|e| DatabaseError::Io(e.kind()))?;
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+
+    /// Checks that `data` is a structurally sound database without decoding it.
+    ///
+    /// Walks the layout like [`Geocoder::from_bytes()`] - header, string table, place
+    /// columns, alternate names and grid - but only checks that every declared section
+    /// fits in the data, that the strings are UTF-8, that the grid keys are sorted and
+    /// that every string index, place index and offset is in range. No strings or
+    /// columns are copied, so this is a cheap way to reject a corrupt
+    /// download before handing it to `reload_from_bytes()` (`reload` feature) or to
+    /// assert in CI that a release artifact is sound. Data that passes also loads
+    /// with [`Geocoder::from_bytes()`]. A compressed database is decompressed into a
+    /// temporary buffer first (`zstd` or `xz` feature).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same [`DatabaseError`] that [`Geocoder::from_bytes()`] would.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use genom::Geocoder;
+    ///
+    /// let data = std::fs::read("places.bin")?;
+    /// let info = Geocoder::validate_bytes(&data)?;
+    /// println!("{} places, built {:?}", info.place_count, info.built_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Corrupt data is an error, never a panic:
+    ///
+    /// ```
+    /// use genom::types::Database;
+    /// use genom::{DatabaseError, Geocoder};
+    ///
+    /// // Header and a string table of one string whose length never terminates
+    /// let mut data = Database::MAGIC.to_vec();
+    /// data.extend(Database::FORMAT_VERSION.to_le_bytes());
+    /// data.extend([0; 10]);
+    /// data.extend(1u64.to_le_bytes());
+    /// data.extend([0xFF; 11]);
+    ///
+    /// assert_eq!(Geocoder::validate_bytes(&data).unwrap_err(), DatabaseError::Truncated);
+    /// assert_eq!(Geocoder::from_bytes(&data).err(), Some(DatabaseError::Truncated));
+    /// ```
+    pub fn validate_bytes(data: &[u8]) -> Result<DatabaseInfo, DatabaseError> {
+        if data.starts_with(&ZSTD_MAGIC) {
+            #[cfg(feature = "zstd")]
+            return Self::validate_layout(&zstd::decode_all(data)?);
+            #[cfg(not(feature = "zstd"))]
+            return Err(DatabaseError::UnsupportedCompression("zstd"));
+        }
+        if data.starts_with(&XZ_MAGIC) {
+            #[cfg(feature = "xz")]
+            {
+                let mut decompressed = Vec::new();
+                lzma_rust2::XzReader::new(data, true).read_to_end(&mut decompressed)?;
+                return Self::validate_layout(&decompressed);
+            }
+            #[cfg(not(feature = "xz"))]
+            return Err(DatabaseError::UnsupportedCompression("xz"));
+        }
+        Self::validate_layout(data)
+    }
+
+    fn validate_layout(mut data: &[u8]) -> Result<DatabaseInfo, DatabaseError> {
+        if take_array(&mut data)? != Database::MAGIC {
+            return Err(DatabaseError::InvalidMagic);
+        }
+        let version = u32::from_le_bytes(take_array(&mut data)?);
+        if version != Database::FORMAT_VERSION {
+            return Err(DatabaseError::UnsupportedVersion(version));
+        }
+        let built_at = u64::from_le_bytes(take_array(&mut data)?);
+        let data_date = u16::from_le_bytes(take_array(&mut data)?);
+
+        let str_count = u64::from_le_bytes(take_array(&mut data)?);
+        for _ in 0..str_count {
+            let str_len = Self::read_varint(&mut data)?;
+            let bytes = take(&mut data, usize::try_from(str_len).unwrap_or(usize::MAX), 1)?;
+            std::str::from_utf8(bytes).map_err(|_| DatabaseError::InvalidUtf8)?;
+        }
+
+        let str_count = usize::try_from(str_count).unwrap_or(usize::MAX);
+
+        // Two coordinate and ten string-index columns of u32, then the u16 dates and the
+        // u32 populations
+        let place_count = u64::from_le_bytes(take_array(&mut data)?) as usize;
+        take(&mut data, place_count, 2 * 4)?;
+        let string_columns = take(&mut data, place_count, 10 * 4)?;
+        take(&mut data, place_count, 2 + 4)?;
+        let name_offsets = take(&mut data, place_count.saturating_add(1), 4)?;
+        let names = take(&mut data, last_u32(name_offsets), 8)?;
+
+        let cell_count = u64::from_le_bytes(take_array(&mut data)?) as usize;
+        let keys = take(&mut data, cell_count, 4)?;
+        let offsets = take(&mut data, cell_count.saturating_add(1), 4)?;
+        let indices = take(&mut data, last_u32(offsets), 4)?;
+
+        // Same checks in the same order as the loader, so both report the same error
+        check_grid_keys(keys.chunks_exact(4).map(|b| {
+            (
+                i16::from_le_bytes([b[0], b[1]]),
+                i16::from_le_bytes([b[2], b[3]]),
+            )
+        }))?;
+        check_indices(le_u32s(string_columns), str_count)?;
+        check_indices(le_u32s(names), str_count)?;
+        check_offsets(le_u32s(name_offsets))?;
+        check_offsets(le_u32s(offsets))?;
+        check_indices(le_u32s(indices), place_count)?;
+
+        Ok(DatabaseInfo::from_header(built_at, data_date, place_count))
+    }
+
+    /// Loads the given countries from a directory of per-country database shards.
+    ///
+    /// The directory is the output of `Builder::build_sharded` (`build-database --shards
+    /// <dir>`): one `{CC}.bin` database per country plus an `index.tsv` listing them.
+    /// Only the requested shards are read and merged into a single database, so an app
+    /// that serves a few countries can ship and load just those. Country codes are
+    /// case-insensitive; duplicates are loaded once.
+    ///
+    /// Lookups never return places from other countries, even near a border.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Io`] if the index or a shard cannot be read,
+    /// [`DatabaseError::MissingShard`] if the index has no shard for a requested country,
+    /// and any decoding error of a shard.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::from_shards("shards", &["FR", "CH"])?;
+    /// let place = geocoder.lookup(46.2044, 6.1432).unwrap();
+    /// assert_eq!(place.country_code, "CH");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_shards(
+        dir: impl AsRef<std::path::Path>,
+        countries: &[&str],
+    ) -> Result<Self, DatabaseError> {
+        let dir = dir.as_ref();
+        let index = std::fs::read_to_string(dir.join(SHARD_INDEX))
+            .map_err(|e| DatabaseError::Io(e.kind()))?;
+        let available: Vec<&str> = index
+            .lines()
+            .filter_map(|line| line.split('\t').next())
+            .collect();
+
+        let mut countries: Vec<String> = countries
+            .iter()
+            .map(|country| country.trim().to_ascii_uppercase())
+            .collect();
+        countries.sort_unstable();
+        countries.dedup();
+
+        let mut db = Database::default();
+        for country in countries {
+            if !available.contains(&country.as_str()) {
+                return Err(DatabaseError::MissingShard(country));
+            }
+            let file = std::fs::File::open(dir.join(format!("{}.bin", country)))
+                .map_err(|e| DatabaseError::Io(e.kind()))?;
+            db.append(Self::load_database(std::io::BufReader::new(file))?);
+        }
+        Ok(Self::from_database(db))
+    }
+
+    /// Wraps an already decoded [`Database`] in a geocoder.
+    ///
+    /// This is the counterpart of [`Geocoder::from_bytes()`] for databases built in
+    /// memory, such as the fixture database of the `test-fixtures` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::types::Database;
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::from_database(Database::default());
+    /// assert!(geocoder.lookup(48.8566, 2.3522).is_none());
+    /// ```
+    pub fn from_database(mut db: Database) -> Self {
+        db.reserve_empty_string();
+        Self {
+            db,
+            countries: OnceLock::new(),
+            timezones: OnceLock::new(),
+            coverage: OnceLock::new(),
+            postal_index: OnceLock::new(),
+            name_index: OnceLock::new(),
+            kd_tree: OnceLock::new(),
+            metric: Box::new(Equirectangular),
+            early_stop: true,
+            #[cfg(feature = "simd")]
+            vectorize: true,
+        }
+    }
+
+    /// Replaces the distance model used to rank candidate places.
+    ///
+    /// The default is [`Equirectangular`](crate::Equirectangular). The metric applies
+    /// to every grid-based lookup; [`Geocoder::lookup_exact()`] always ranks by
+    /// great-circle distance. The global geocoder keeps the default, so configure an
+    /// instance created with [`Geocoder::from_bytes()`] or [`Geocoder::from_database()`].
+    ///
+    /// With the `simd` feature, [`Geocoder::lookup()`] ranks dense cells with a
+    /// vectorized equirectangular scan. Any other metric turns that scan off, so the
+    /// configured metric ranks every candidate.
+    ///
+    /// Searches beyond the 3×3 neighborhood stop early only with the built-in metrics,
+    /// whose distances are great-circle kilometers or close to them. Any other metric
+    /// searches every ring up to the lookup's limit; see [`DistanceMetric`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::types::Database;
+    /// use genom::{Ellipsoidal, Geocoder};
+    ///
+    /// let geocoder = Geocoder::from_database(Database::default()).with_metric(Ellipsoidal);
+    /// assert!(geocoder.lookup(48.8566, 2.3522).is_none());
+    /// ```
+    pub fn with_metric(mut self, metric: impl DistanceMetric + 'static) -> Self {
+        let any = &metric as &dyn std::any::Any;
+        self.early_stop =
+            any.is::<Equirectangular>() || any.is::<Haversine>() || any.is::<Ellipsoidal>();
+        #[cfg(feature = "simd")]
+        {
+            self.vectorize = any.is::<Equirectangular>();
+        }
+        self.metric = Box::new(metric);
+        self
+    }
+
+    /// Decodes the embedded database. A missing one, or one without any places (for
+    /// example when every country download was skipped), is an error rather than a
+    /// geocoder that silently finds nothing.
+    fn new() -> Result<Self, DatabaseError> {
+        if DATA.is_empty() {
+            return Err(DatabaseError::EmptyDatabase);
+        }
+        let geocoder = Self::from_bytes(DATA)?;
+        if geocoder.db.is_empty() {
+            return Err(DatabaseError::EmptyDatabase);
+        }
+        Ok(geocoder)
+    }
+
+    /// Decodes a database, decompressing it first if it starts with a zstd frame or an
+    /// xz stream.
+    fn load_database(mut reader: impl Read) -> Result<Database, DatabaseError> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        let reader = (&magic[..]).chain(reader);
+        if magic == ZSTD_MAGIC {
+            #[cfg(feature = "zstd")]
+            return Self::read_database(zstd::Decoder::new(reader)?);
+            #[cfg(not(feature = "zstd"))]
+            return Err(DatabaseError::UnsupportedCompression("zstd"));
+        }
+        if magic == XZ_MAGIC {
+            #[cfg(feature = "xz")]
+            return Self::read_database(lzma_rust2::XzReader::new(reader, true));
+            #[cfg(not(feature = "xz"))]
+            return Err(DatabaseError::UnsupportedCompression("xz"));
+        }
+        Self::read_database(reader)
+    }
+
+    fn read_database(mut reader: impl Read) -> Result<Database, DatabaseError> {
         let mut buf2 = [0u8; 2];
+        let mut buf4 = [0u8; 4];
+        let mut buf8 = [0u8; 8];
 
-        cursor.read_exact(&mut buf8)?;
+        reader.read_exact(&mut buf4)?;
+        if buf4 != Database::MAGIC {
+            return Err(DatabaseError::InvalidMagic);
+        }
+        reader.read_exact(&mut buf4)?;
+        let version = u32::from_le_bytes(buf4);
+        if version != Database::FORMAT_VERSION {
+            return Err(DatabaseError::UnsupportedVersion(version));
+        }
+        reader.read_exact(&mut buf8)?;
+        let built_at = u64::from_le_bytes(buf8);
+        reader.read_exact(&mut buf2)?;
+        let data_date = u16::from_le_bytes(buf2);
+
+        reader.read_exact(&mut buf8)?;
         let str_count = u64::from_le_bytes(buf8) as usize;
-        let mut strings = Vec::with_capacity(str_count);
+        let mut strings = Vec::with_capacity(str_count.min(MAX_PREALLOCATED));
         for _ in 0..str_count {
-            let str_len = Self::read_varint(&mut cursor)? as usize;
-            let mut str_buf = vec![0u8; str_len];
-            cursor.read_exact(&mut str_buf)?;
+            let str_len = Self::read_varint(&mut reader)?;
+            let mut str_buf = Vec::new();
+            reader.by_ref().take(str_len).read_to_end(&mut str_buf)?;
+            if str_buf.len() as u64 != str_len {
+                return Err(DatabaseError::Truncated);
+            }
             strings.push(String::from_utf8(str_buf)?);
         }
 
-        cursor.read_exact(&mut buf8)?;
+        reader.read_exact(&mut buf8)?;
         let place_count = u64::from_le_bytes(buf8) as usize;
-        let mut places = Vec::with_capacity(place_count);
-        for _ in 0..place_count {
-            cursor.read_exact(&mut buf4)?;
-            let city = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let region = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let region_code = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let district = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let country_code = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let postal_code = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let timezone = u32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let lat = i32::from_le_bytes(buf4);
-            cursor.read_exact(&mut buf4)?;
-            let lon = i32::from_le_bytes(buf4);
-            places.push(crate::types::CompactPlace {
-                city,
-                region,
-                region_code,
-                district,
-                country_code,
-                postal_code,
-                timezone,
-                lat,
-                lon,
-            });
-        }
-
-        cursor.read_exact(&mut buf8)?;
-        let grid_count = u64::from_le_bytes(buf8) as usize;
-        let mut grid = rustc_hash::FxHashMap::default();
-        for _ in 0..grid_count {
-            cursor.read_exact(&mut buf2)?;
-            let key_lat = i16::from_le_bytes(buf2);
-            cursor.read_exact(&mut buf2)?;
-            let key_lon = i16::from_le_bytes(buf2);
-            cursor.read_exact(&mut buf8)?;
-            let vec_len = u64::from_le_bytes(buf8) as usize;
-            let mut indices = Vec::with_capacity(vec_len);
-            for _ in 0..vec_len {
-                cursor.read_exact(&mut buf4)?;
-                indices.push(u32::from_le_bytes(buf4));
-            }
-            grid.insert((key_lat, key_lon), indices);
+        let lats = Self::read_column(&mut reader, place_count, i32::from_le_bytes)?;
+        let lons = Self::read_column(&mut reader, place_count, i32::from_le_bytes)?;
+        let cities = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let cities_ascii = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let regions = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let region_codes = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let districts = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let subdistricts = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let country_codes = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let postal_codes = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let timezones = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let feature_codes = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let modified = Self::read_column(&mut reader, place_count, u16::from_le_bytes)?;
+        let populations = Self::read_column(&mut reader, place_count, u32::from_le_bytes)?;
+        let name_offsets = Self::read_column(&mut reader, place_count + 1, u32::from_le_bytes)?;
+        let name_count = *name_offsets.last().unwrap_or(&0) as usize;
+        let names = Self::read_column(&mut reader, name_count, |b: [u8; 8]| {
+            (
+                u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+                u32::from_le_bytes([b[4], b[5], b[6], b[7]]),
+            )
+        })?;
+
+        reader.read_exact(&mut buf8)?;
+        let cell_count = u64::from_le_bytes(buf8) as usize;
+        let keys = Self::read_column(&mut reader, cell_count, |b: [u8; 4]| {
+            (
+                i16::from_le_bytes([b[0], b[1]]),
+                i16::from_le_bytes([b[2], b[3]]),
+            )
+        })?;
+        let offsets = Self::read_column(&mut reader, cell_count + 1, u32::from_le_bytes)?;
+        let index_count = *offsets.last().unwrap_or(&0) as usize;
+        let indices = Self::read_column(&mut reader, index_count, u32::from_le_bytes)?;
+        check_grid_keys(keys.iter().copied())?;
+
+        // Lookups index without bounds handling, so every stored index is checked here
+        for column in [
+            &cities,
+            &cities_ascii,
+            &regions,
+            &region_codes,
+            &districts,
+            &subdistricts,
+            &country_codes,
+            &postal_codes,
+            &timezones,
+            &feature_codes,
+        ] {
+            check_indices(column.iter().copied(), strings.len())?;
         }
+        check_indices(
+            names.iter().flat_map(|&(lang, name)| [lang, name]),
+            strings.len(),
+        )?;
+        check_offsets(name_offsets.iter().copied())?;
+        check_offsets(offsets.iter().copied())?;
+        check_indices(indices.iter().copied(), place_count)?;
+        let grid = Grid {
+            keys,
+            offsets,
+            indices,
+        };
 
         Ok(Database {
+            built_at,
+            data_date,
             strings,
-            places,
+            lats,
+            lons,
+            cities,
+            cities_ascii,
+            regions,
+            region_codes,
+            districts,
+            subdistricts,
+            country_codes,
+            postal_codes,
+            timezones,
+            feature_codes,
+            modified,
+            populations,
+            name_offsets,
+            names,
             grid,
         })
     }
 
-    fn read_varint(cursor: &mut std::io::Cursor<&[u8]>) -> Result<u64, Box<dyn std::error::Error>> {
-        use std::io::Read;
+    /// Reads `len` fixed-size values, decoding them in chunks so the raw column bytes
+    /// are never buffered in full. The declared length only bounds the initial
+    /// allocation up to [`MAX_PREALLOCATED`], so a corrupt count fails with
+    /// [`DatabaseError::Truncated`] instead of a huge allocation.
+    fn read_column<T, const N: usize>(
+        reader: &mut impl Read,
+        len: usize,
+        decode: impl Fn([u8; N]) -> T,
+    ) -> Result<Vec<T>, DatabaseError> {
+        len.checked_mul(N).ok_or(DatabaseError::Truncated)?;
+        let mut values = Vec::with_capacity(len.min(MAX_PREALLOCATED));
+        let mut chunk = vec![0u8; N * len.clamp(1, COLUMN_CHUNK)];
+        let mut remaining = len;
+        while remaining > 0 {
+            let count = remaining.min(COLUMN_CHUNK);
+            let bytes = &mut chunk[..count * N];
+            reader.read_exact(bytes)?;
+            values.extend(
+                bytes
+                    .chunks_exact(N)
+                    .map(|value| decode(value.try_into().unwrap())),
+            );
+            remaining -= count;
+        }
+        Ok(values)
+    }
+
+    /// Reads an LEB128 varint. An encoding that does not fit in 64 bits can only come
+    /// from corrupt data and is rejected as [`DatabaseError::Truncated`], like any other
+    /// length the data cannot hold.
+    fn read_varint(reader: &mut impl Read) -> Result<u64, DatabaseError> {
         let mut result = 0u64;
         let mut shift = 0;
         loop {
             let mut byte = [0u8; 1];
-            cursor.read_exact(&mut byte)?;
-            result |= ((byte[0] & 0x7F) as u64) << shift;
+            reader.read_exact(&mut byte)?;
+            let bits = (byte[0] & 0x7F) as u64;
+            if shift >= 64 || (shift == 63 && bits > 1) {
+                return Err(DatabaseError::Truncated);
+            }
+            result |= bits << shift;
             if (byte[0] & 0x80) == 0 {
                 break;
             }
@@ -181,9 +715,15 @@ impl Geocoder {
     ///
     /// 1. Quantize coordinates to grid key (0.1° resolution)
     /// 2. Search target cell and 8 neighboring cells
-    /// 3. Calculate haversine distance to all candidates
+    /// 3. Calculate the distance to all candidates with the configured
+    ///    [`DistanceMetric`] (equirectangular by default: equivalent to haversine for
+    ///    ranking at this scale, but cheaper)
     /// 4. Return nearest place, enriched with metadata
     ///
+    /// Places at exactly the same distance (e.g. two records at one coordinate) are
+    /// resolved in favor of the lower database index, so the same query against the
+    /// same database always returns the same place.
+    ///
     /// # Returns
     ///
     /// `Some(Place)` if a location is found within search radius, `None` otherwise.
@@ -204,47 +744,1567 @@ impl Geocoder {
     /// # }
     /// ```
     pub fn lookup(&self, latitude: f64, longitude: f64) -> Option<Place> {
+        self.lookup_with_options(latitude, longitude, &LookupOptions::default())
+    }
+
+    /// Finds the nearest place subject to the constraints in `options`.
+    ///
+    /// A single entry point for constrained searches: a distance limit, a wider or
+    /// narrower search radius, country and feature code filters, and postal code
+    /// preference can be combined freely. See [`LookupOptions`] for each setting. With
+    /// the default options this is exactly [`Geocoder::lookup()`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::{Geocoder, LookupOptions};
+    ///
+    /// // Near the Belgian border, but only French places within 20 km
+    /// let options = LookupOptions::default()
+    ///     .with_allowed_countries(&["FR"])
+    ///     .with_max_rings(3)
+    ///     .with_max_distance_km(20.0);
+    /// let place = Geocoder::global().lookup_with_options(50.69, 3.18, &options);
+    /// assert!(place.is_none_or(|place| place.country_code == "FR"));
+    /// # }
+    /// ```
+    pub fn lookup_with_options(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        options: &LookupOptions,
+    ) -> Option<Place> {
         let location = Location::new(latitude, longitude);
         let grid_key = self.grid_key(&location);
-        let idx = self.find_nearest(&location, grid_key)?;
+        let max_ring = options.max_rings.min(MAX_GRID_RING as u16) as i16;
+        let matches = |idx: usize| {
+            let listed = |list: &[String], column: &[u32]| {
+                let value = &self.db.strings[column[idx] as usize];
+                list.is_empty() || list.iter().any(|item| item.eq_ignore_ascii_case(value))
+            };
+            listed(&options.allowed_countries, &self.db.country_codes)
+                && listed(&options.feature_codes, &self.db.feature_codes)
+        };
+
+        let mut debug = LookupDebug::default();
+        let unfiltered = options.allowed_countries.is_empty() && options.feature_codes.is_empty();
+        let mut idx = if unfiltered && max_ring == 1 {
+            self.find_nearest_traced(&location, grid_key, &mut debug)
+        } else {
+            self.find_nearest_matching(&location, grid_key, max_ring, matches, &mut debug)
+        }?;
+        let nearest_km = debug.nearest_distance_km.unwrap_or(0.0);
+        let max_km = options.max_distance_km.unwrap_or(f64::INFINITY);
+        if nearest_km > max_km {
+            return None;
+        }
+
+        let has_postal = |idx: usize| self.db.postal_codes[idx] != Database::EMPTY_STRING;
+        if options.prefer_postal && !has_postal(idx) {
+            let mut postal_debug = LookupDebug::default();
+            let postal = self.find_nearest_matching(
+                &location,
+                grid_key,
+                max_ring,
+                |idx| has_postal(idx) && matches(idx),
+                &mut postal_debug,
+            );
+            let postal_km = postal_debug.nearest_distance_km.unwrap_or(f64::INFINITY);
+            if let Some(postal) = postal {
+                if postal_km - nearest_km <= POSTAL_TIE_KM && postal_km <= max_km {
+                    idx = postal;
+                }
+            }
+        }
         Some(self.build_place(idx))
     }
 
-    fn grid_key(&self, location: &Location) -> (i16, i16) {
-        (
-            ((location.latitude * 100000.0) as i32 / 10000) as i16,
-            ((location.longitude * 100000.0) as i32 / 10000) as i16,
-        )
+    /// Finds the nearest place to the given coordinates without allocating.
+    ///
+    /// Behaves like [`Geocoder::lookup()`] but returns a [`PlaceRef`] whose string
+    /// fields borrow from the database. This avoids cloning every string field out of
+    /// the string table, which dominates lookup cost in high-throughput loops that only
+    /// read a few fields. Call [`PlaceRef::to_owned`] when an owned [`Place`] is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let place = geocoder.lookup_ref(35.6762, 139.6503).unwrap();
+    /// assert_eq!(place.country_code, "JP");
+    /// # }
+    /// ```
+    pub fn lookup_ref(&self, latitude: f64, longitude: f64) -> Option<PlaceRef<'_>> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let idx = self.find_nearest(&location, grid_key)?;
+        Some(enrich_place_ref(self.place_input(idx)))
     }
 
-    fn find_nearest(&self, location: &Location, grid_key: (i16, i16)) -> Option<usize> {
-        (-1..=1)
-            .flat_map(|dlat| {
-                (-1..=1).filter_map(move |dlon| {
-                    self.db.grid.get(&(grid_key.0 + dlat, grid_key.1 + dlon))
-                })
-            })
-            .flatten()
-            .map(|&idx| {
-                let place = &self.db.places[idx as usize];
-                (idx as usize, location.distance_to(&place.location()))
-            })
-            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-            .map(|(idx, _)| idx)
+    /// Finds the place with the smallest great-circle distance to the given coordinates.
+    ///
+    /// [`Geocoder::lookup()`] only searches the grid cells around the query, so it can
+    /// miss a nearer place just outside them, and returns `None` when none of them holds
+    /// a place. This method searches a k-d tree over every place instead and always
+    /// returns the true nearest place (only `None` for an empty database), including
+    /// across the antimeridian and near the poles.
+    ///
+    /// The tree is built on the first call, which takes a moment for the full database
+    /// and keeps about 32 bytes per place in memory. Queries cost O(log n) on average,
+    /// a few times slower than [`Geocoder::lookup()`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// // Far out in the Pacific: the grid search finds nothing, the exact search does
+    /// let geocoder = Geocoder::global();
+    /// assert!(geocoder.lookup(-30.0, -140.0).is_none());
+    /// let place = geocoder.lookup_exact(-30.0, -140.0).unwrap();
+    /// println!("nearest land: {}", place);
+    /// # }
+    /// ```
+    pub fn lookup_exact(&self, latitude: f64, longitude: f64) -> Option<Place> {
+        let idx = self
+            .kd_tree
+            .get_or_init(|| KdTree::build(&self.db))
+            .nearest(&Location::new(latitude, longitude))?;
+        Some(self.build_place(idx))
     }
 
-    fn build_place(&self, idx: usize) -> Place {
-        let place = &self.db.places[idx];
-        enrich_place(PlaceInput {
-            city: &self.db.strings[place.city as usize],
-            region: &self.db.strings[place.region as usize],
-            region_code: &self.db.strings[place.region_code as usize],
-            district: &self.db.strings[place.district as usize],
-            country_code: &self.db.strings[place.country_code as usize],
-            postal_code: &self.db.strings[place.postal_code as usize],
-            timezone: &self.db.strings[place.timezone as usize],
-            latitude: place.lat as f64 / 100000.0,
-            longitude: place.lon as f64 / 100000.0,
-        })
+    /// Finds the nearest place to the center of an H3 cell.
+    ///
+    /// Requires the `h3` feature. The cell is resolved to its center, which is then
+    /// looked up like [`Geocoder::lookup()`]. Returns `None` if `cell` is not a valid H3
+    /// cell index or no place lies near its center.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::{Geocoder, Location};
+    ///
+    /// let cell = Location::new(48.8566, 2.3522).to_h3(9).unwrap();
+    /// let place = Geocoder::global().lookup_h3(cell).unwrap();
+    /// assert_eq!(place.city, "Paris");
+    /// # }
+    /// ```
+    #[cfg(feature = "h3")]
+    pub fn lookup_h3(&self, cell: u64) -> Option<Place> {
+        let center = h3o::LatLng::from(h3o::CellIndex::try_from(cell).ok()?);
+        self.lookup(center.lat(), center.lng())
+    }
+
+    /// Returns the place at position `idx` in the database, or `None` if `idx` is out of
+    /// bounds.
+    ///
+    /// Indices are stable for a given database file, so a caller can store the compact
+    /// index reported by [`Geocoder::lookup_debug()`] and resolve the full place later
+    /// without repeating the spatial search. Indices differ between database builds.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let (place, debug) = geocoder.lookup_debug(48.8566, 2.3522);
+    /// let idx = debug.place_index.unwrap();
+    /// assert_eq!(geocoder.place_at(idx), place);
+    /// assert!(geocoder.place_at(usize::MAX).is_none());
+    /// # }
+    /// ```
+    pub fn place_at(&self, idx: usize) -> Option<Place> {
+        (idx < self.db.len()).then(|| self.build_place(idx))
     }
+
+    /// Returns the great-circle distance in kilometers from the given coordinates to the
+    /// place at database index `idx`, or `None` if `idx` is out of bounds.
+    ///
+    /// Reads only the place's coordinates, without a search or building a [`Place`].
+    /// Pairs with a stored [`LookupDebug::place_index`] to track, for example, how far a
+    /// user has moved from their registered city.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let (_, debug) = geocoder.lookup_debug(48.8566, 2.3522);
+    /// let home = debug.place_index.unwrap();
+    ///
+    /// // Later, from Versailles
+    /// let km = geocoder.distance_to_place(48.8049, 2.1204, home).unwrap();
+    /// assert!(km > 15.0 && km < 20.0);
+    /// # }
+    /// ```
+    pub fn distance_to_place(&self, latitude: f64, longitude: f64, idx: usize) -> Option<f64> {
+        (idx < self.db.len())
+            .then(|| Location::new(latitude, longitude).distance_to(&self.db.location(idx)))
+    }
+
+    /// Finds the nearest place other than the one at database index `exclude_idx`.
+    ///
+    /// Useful for "somewhere else nearby" suggestions when the nearest place is the
+    /// user's current location: pass the index reported in
+    /// [`LookupDebug::place_index`]. An index that matches no place excludes nothing.
+    ///
+    /// Since the excluded place may be the only one in its neighborhood, the search
+    /// expands ring by ring up to 10 cells (~110 km at the equator) like
+    /// [`Geocoder::lookup_hint_country()`], and returns the nearest remaining place
+    /// within that radius.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let (nearest, debug) = geocoder.lookup_debug(48.8566, 2.3522);
+    /// let other = geocoder
+    ///     .lookup_excluding(48.8566, 2.3522, debug.place_index.unwrap())
+    ///     .unwrap();
+    /// assert_ne!(Some(other), nearest);
+    /// # }
+    /// ```
+    pub fn lookup_excluding(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        exclude_idx: usize,
+    ) -> Option<Place> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let idx = self.find_nearest_matching(
+            &location,
+            grid_key,
+            MAX_SEARCH_RING,
+            |idx| idx != exclude_idx,
+            &mut LookupDebug::default(),
+        )?;
+        Some(self.build_place(idx))
+    }
+
+    /// Finds the nearest place together with up to `context_count` further places,
+    /// nearest first.
+    ///
+    /// The context places give a sense of the surrounding area: the suburbs around a
+    /// city center, or the neighboring villages of a rural coordinate. The primary place
+    /// never appears in the context list. Fewer than `context_count` places are returned
+    /// when the neighborhood runs out.
+    ///
+    /// The search expands ring by ring up to 10 cells (~110 km at the equator) like
+    /// [`Geocoder::lookup_hint_country()`] until the requested number of places is found.
+    /// Returns `None` if no place lies within that radius.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let (place, context) = Geocoder::global()
+    ///     .lookup_with_context(48.8566, 2.3522, 5)
+    ///     .unwrap();
+    /// println!("{} near {}", place.city, context[0].city);
+    /// assert!(context.len() <= 5);
+    /// # }
+    /// ```
+    ///
+    /// `context_count` only limits the result, so `usize::MAX` asks for every place
+    /// within the search radius:
+    ///
+    /// ```
+    /// use genom::types::Database;
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::from_database(Database::default());
+    /// assert!(geocoder.lookup_with_context(48.8566, 2.3522, usize::MAX).is_none());
+    /// ```
+    pub fn lookup_with_context(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        context_count: usize,
+    ) -> Option<(Place, Vec<Place>)> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let nearest = self.find_k_nearest(
+            &location,
+            grid_key,
+            context_count.saturating_add(1),
+            MAX_SEARCH_RING,
+        );
+        let (&(primary, _), context) = nearest.split_first()?;
+        Some((
+            self.build_place(primary),
+            context
+                .iter()
+                .map(|&(idx, _)| self.build_place(idx))
+                .collect(),
+        ))
+    }
+
+    /// Returns every place whose distance is within `threshold_km` of the nearest
+    /// place's distance, nearest first.
+    ///
+    /// Near borders and tripoints a single nearest place hides real ambiguity: a
+    /// coordinate 2.0 km from a town in one region and 2.3 km from a town in another
+    /// could belong to either. With a threshold of 0.5 km both are returned, so callers
+    /// can show "region A or region B" instead of a confident single answer. The cutoff
+    /// is relative to the nearest distance, so it stays meaningful in sparse areas where
+    /// every place is far away. A negative threshold is treated as 0, which returns the
+    /// nearest place and any place at exactly the same distance.
+    ///
+    /// The search expands ring by ring up to 10 cells (~110 km at the equator) like
+    /// [`Geocoder::lookup_hint_country()`]. Returns an empty vector if no place lies
+    /// within that radius.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// // Near the tripoint of France, Germany and Switzerland
+    /// let candidates = Geocoder::global().lookup_ambiguous(47.5896, 7.5886, 1.0);
+    /// for place in &candidates {
+    ///     println!("{}, {} ({})", place.city, place.region, place.country_code);
+    /// }
+    /// # }
+    /// ```
+    pub fn lookup_ambiguous(&self, latitude: f64, longitude: f64, threshold_km: f64) -> Vec<Place> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let mut debug = LookupDebug::default();
+        if self
+            .find_nearest_matching(&location, grid_key, MAX_SEARCH_RING, |_| true, &mut debug)
+            .is_none()
+        {
+            return Vec::new();
+        }
+        let radius = debug.nearest_distance_km.unwrap_or(0.0) + threshold_km.max(0.0);
+        self.find_within(&location, grid_key, radius, MAX_SEARCH_RING)
+            .into_iter()
+            .map(|(idx, _)| self.build_place(idx))
+            .collect()
+    }
+
+    /// Finds the nearest place and reports how the search went.
+    ///
+    /// Returns the same place as [`Geocoder::lookup()`] together with a [`LookupDebug`]
+    /// describing the grid cells and candidates examined. Useful for diagnosing slow
+    /// lookups in dense areas and misses in sparse ones.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let (place, debug) = Geocoder::global().lookup_debug(35.6762, 139.6503);
+    /// println!(
+    ///     "{:?}: {} candidates in {} cells, nearest {:?} km",
+    ///     place.map(|p| p.city),
+    ///     debug.candidates_checked,
+    ///     debug.grid_cells_searched,
+    ///     debug.nearest_distance_km
+    /// );
+    /// # }
+    /// ```
+    pub fn lookup_debug(&self, latitude: f64, longitude: f64) -> (Option<Place>, LookupDebug) {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let mut debug = LookupDebug::default();
+        let place = self
+            .find_nearest_traced(&location, grid_key, &mut debug)
+            .map(|idx| self.build_place(idx));
+        (place, debug)
+    }
+
+    /// Finds the nearest place to the given coordinates within a known country.
+    ///
+    /// Behaves like [`Geocoder::lookup()`] but only considers places whose country code
+    /// matches `country_code` (case-insensitive). Use it when the country is already
+    /// known from another source, such as a billing address, so that a coordinate near
+    /// a border does not resolve to the neighboring country.
+    ///
+    /// If no matching place lies in the 3×3 cell neighborhood, the search expands ring
+    /// by ring up to 10 cells (~110 km at the equator) from the query cell. After the
+    /// first match it keeps expanding until no unsearched cell can hold a closer place,
+    /// so the result is the nearest matching place within that radius.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// // Near the French border in Geneva
+    /// let place = Geocoder::global()
+    ///     .lookup_hint_country(46.1950, 6.2100, "CH")
+    ///     .unwrap();
+    /// assert_eq!(place.country_code, "CH");
+    /// # }
+    /// ```
+    pub fn lookup_hint_country(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        country_code: &str,
+    ) -> Option<Place> {
+        self.lookup_hint_country_debug(latitude, longitude, country_code)
+            .0
+    }
+
+    /// Finds the nearest place within a known country and reports how the search went.
+    ///
+    /// Returns the same place as [`Geocoder::lookup_hint_country()`] together with a
+    /// [`LookupDebug`]. Its `rings_expanded` tells how far the search had to widen: `0`
+    /// means the match lies in the 3×3 neighborhood of the query cell (nearby, high
+    /// confidence), while a match several rings out is tens of kilometers away, e.g. for
+    /// an offshore coordinate or one deep in another country.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let (place, debug) = Geocoder::global().lookup_hint_country_debug(46.1950, 6.2100, "CH");
+    /// if debug.rings_expanded > 2 {
+    ///     println!("low confidence match: {:?}", place.map(|p| p.city));
+    /// }
+    /// # }
+    /// ```
+    pub fn lookup_hint_country_debug(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        country_code: &str,
+    ) -> (Option<Place>, LookupDebug) {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let country_code = country_code.trim();
+        let matches = |idx: usize| {
+            self.db.strings[self.db.country_codes[idx] as usize].eq_ignore_ascii_case(country_code)
+        };
+        let mut debug = LookupDebug::default();
+        let place = self
+            .find_nearest_matching(&location, grid_key, MAX_SEARCH_RING, matches, &mut debug)
+            .map(|idx| self.build_place(idx));
+        (place, debug)
+    }
+
+    /// Finds the nearest place accepted by `predicate`.
+    ///
+    /// This is the general form of [`Geocoder::lookup_hint_country()`] and
+    /// [`Geocoder::lookup_nearest_of_type()`] for arbitrary constraints, such as "only
+    /// places with a postal code" or "only places from a saved list". The predicate sees
+    /// each candidate as a [`PlaceRef`], so rejected candidates cost no string
+    /// allocations; only the accepted place is built into a [`Place`].
+    ///
+    /// The search expands ring by ring up to 10 cells (~110 km at the equator) from the
+    /// query cell and returns the nearest accepted place within that radius, or `None`
+    /// if there is none. Candidates are checked nearest ring first, but not in distance
+    /// order, so the predicate may be called for places farther than the result.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let place = Geocoder::global()
+    ///     .lookup_filtered(48.8566, 2.3522, |place| place.postal_code.starts_with("92"))
+    ///     .unwrap();
+    /// assert_eq!(place.region_code, "IDF");
+    /// # }
+    /// ```
+    pub fn lookup_filtered(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        predicate: impl Fn(&PlaceRef<'_>) -> bool,
+    ) -> Option<Place> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let idx = self.find_nearest_matching(
+            &location,
+            grid_key,
+            MAX_SEARCH_RING,
+            |idx| predicate(&enrich_place_ref(self.place_input(idx))),
+            &mut LookupDebug::default(),
+        )?;
+        Some(self.build_place(idx))
+    }
+
+    /// Finds the nearest place whose GeoNames feature code is one of `codes`.
+    ///
+    /// Useful for questions like "which capital is this coordinate closest to" (`PPLC`)
+    /// or "which administrative seat serves this rural area" (`PPLA`, `PPLA2`). Codes are
+    /// compared case-insensitively. The database contains `PPL`, `PPLA` to `PPLA4`,
+    /// `PPLC`, `PPLG` and `PPLS` places.
+    ///
+    /// The search expands ring by ring up to 10 cells (~110 km at the equator) from the
+    /// query cell. Since places of sparse types such as capitals are often farther away,
+    /// it then falls back to the nearest matching place by great-circle distance over the
+    /// whole database. Returns `None` only if no place has any of the codes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// // A village in Brandenburg: the nearest national capital is Berlin
+    /// let capital = Geocoder::global()
+    ///     .lookup_nearest_of_type(52.2, 14.0, &["PPLC"])
+    ///     .unwrap();
+    /// assert_eq!(capital.city, "Berlin");
+    /// # }
+    /// ```
+    pub fn lookup_nearest_of_type(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        codes: &[&str],
+    ) -> Option<Place> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let matches = |idx: usize| {
+            let code = &self.db.strings[self.db.feature_codes[idx] as usize];
+            codes.iter().any(|wanted| wanted.eq_ignore_ascii_case(code))
+        };
+
+        let idx = self
+            .find_nearest_matching(
+                &location,
+                grid_key,
+                MAX_SEARCH_RING,
+                matches,
+                &mut LookupDebug::default(),
+            )
+            .or_else(|| {
+                (0..self.db.len())
+                    .filter(|&idx| matches(idx))
+                    .map(|idx| (idx, location.distance_to(&self.db.location(idx))))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(idx, _)| idx)
+            })?;
+        Some(self.build_place(idx))
+    }
+
+    /// Finds the nearest place, or names the ocean or sea if no place is nearby.
+    ///
+    /// Returns [`LookupResult::Place`] whenever [`Geocoder::lookup()`] finds a place.
+    /// Otherwise the coordinates are classified into one of the major oceans and seas by
+    /// a coarse built-in table, so marine tracks get "North Atlantic Ocean" instead of
+    /// `None`. The classification does not model coastlines: it is reliable for open
+    /// water, approximate near the boundary of two water bodies, and also names a water
+    /// body for remote land with no place nearby (deserts, ice sheets).
+    ///
+    /// Requires the `water` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::{Geocoder, LookupResult};
+    ///
+    /// let result = Geocoder::global().lookup_or_water(35.0, -40.0);
+    /// assert_eq!(result.name(), "North Atlantic Ocean");
+    /// assert!(matches!(result, LookupResult::Water(_)));
+    /// # }
+    /// ```
+    #[cfg(feature = "water")]
+    pub fn lookup_or_water(&self, latitude: f64, longitude: f64) -> LookupResult {
+        match self.lookup(latitude, longitude) {
+            Some(place) => LookupResult::Place(place),
+            None => LookupResult::Water(WaterBody {
+                name: crate::water::nearest_water_body(&Location::new(latitude, longitude))
+                    .to_string(),
+            }),
+        }
+    }
+
+    /// Finds the nearest place, preferring one with a postal code on near-ties.
+    ///
+    /// Searches the same 3×3 cell neighborhood as [`Geocoder::lookup()`]. If the nearest
+    /// place has no postal code but another candidate that has one lies at most 250 m
+    /// farther away, that candidate is returned instead. In dense clusters this yields
+    /// the more complete record without changing the result anywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let place = Geocoder::global().lookup_prefer_postal(48.8566, 2.3522).unwrap();
+    /// assert_eq!(place.city, "Paris");
+    /// # }
+    /// ```
+    pub fn lookup_prefer_postal(&self, latitude: f64, longitude: f64) -> Option<Place> {
+        let options = LookupOptions::default().with_prefer_postal(true);
+        self.lookup_with_options(latitude, longitude, &options)
+    }
+
+    /// Finds the nearest place with at least `min_population` inhabitants.
+    ///
+    /// Large cities are sparse, so the search expands up to ~550 km instead of the 3×3
+    /// neighborhood of [`Geocoder::lookup()`]. Places with an unknown population count
+    /// as 0 inhabitants, which excludes many villages for any positive threshold.
+    /// Returns `None` if no place within range qualifies.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// // Versailles is nearest, but Paris is the nearest city of a million people
+    /// let place = Geocoder::global().lookup_min_population(48.8049, 2.1204, 1_000_000);
+    /// assert_eq!(place.unwrap().city, "Paris");
+    /// # }
+    /// ```
+    pub fn lookup_min_population(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        min_population: u32,
+    ) -> Option<Place> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let idx = self.find_nearest_matching(
+            &location,
+            grid_key,
+            POPULATION_SEARCH_RING,
+            |idx| self.db.populations[idx] >= min_population,
+            &mut LookupDebug::default(),
+        )?;
+        Some(self.build_place(idx))
+    }
+
+    /// Returns the spatial grid cell a coordinate falls into.
+    ///
+    /// Cells are 0.1° × 0.1° and keyed by the coordinate in tenths of a degree,
+    /// truncated toward zero. A lookup searches this cell and its eight neighbors, so
+    /// comparing the key of a query with the keys of candidate places shows which
+    /// places were considered.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// assert_eq!(geocoder.grid_key_for(48.8566, 2.3522), (488, 23));
+    /// assert_eq!(geocoder.grid_key_for(-33.8688, 151.2093), (-338, 1512));
+    ///
+    /// let place = geocoder.lookup(48.8566, 2.3522).unwrap();
+    /// println!("nearest place in cell {:?}", geocoder.grid_key_for(place.latitude, place.longitude));
+    /// # }
+    /// ```
+    pub fn grid_key_for(&self, latitude: f64, longitude: f64) -> (i16, i16) {
+        self.grid_key(&Location::new(latitude, longitude))
+    }
+
+    /// Returns the indices of the places in the grid cell `key`, or `None` if the cell
+    /// is empty.
+    ///
+    /// Indices are ascending and can be resolved with [`Geocoder::place_at()`]. Together
+    /// with [`Geocoder::grid_keys()`] this gives read-only access to the spatial index for
+    /// custom neighborhood queries such as density maps.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let key = geocoder.grid_key_for(48.8566, 2.3522);
+    /// for &idx in geocoder.cell(key).unwrap_or_default() {
+    ///     println!("{}", geocoder.place_at(idx as usize).unwrap().city);
+    /// }
+    /// # }
+    /// ```
+    pub fn cell(&self, key: (i16, i16)) -> Option<&[u32]> {
+        self.db.grid.get(key)
+    }
+
+    /// Returns the keys of all non-empty grid cells in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// let densest = geocoder
+    ///     .grid_keys()
+    ///     .max_by_key(|&key| geocoder.cell(key).map_or(0, <[u32]>::len));
+    /// println!("densest cell: {:?}", densest);
+    /// # }
+    /// ```
+    pub fn grid_keys(&self) -> impl Iterator<Item = (i16, i16)> + '_ {
+        self.db.grid.iter().map(|(key, _)| key)
+    }
+
+    /// Returns summary statistics about the loaded database.
+    ///
+    /// The country list is shared with [`Geocoder::countries()`] and computed once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let stats = Geocoder::global().stats();
+    /// println!("{} places in {} cells", stats.place_count, stats.grid_cell_count);
+    /// assert!(stats.countries.iter().any(|c| c == "DE"));
+    /// # }
+    /// ```
+    pub fn stats(&self) -> DatabaseStats {
+        fn allocated<T>(column: &Vec<T>) -> usize {
+            column.capacity() * std::mem::size_of::<T>()
+        }
+
+        let db = &self.db;
+        let strings_bytes =
+            allocated(&db.strings) + db.strings.iter().map(String::capacity).sum::<usize>();
+        let places_bytes = allocated(&db.lats)
+            + allocated(&db.lons)
+            + [
+                &db.cities,
+                &db.cities_ascii,
+                &db.regions,
+                &db.region_codes,
+                &db.districts,
+                &db.subdistricts,
+                &db.country_codes,
+                &db.postal_codes,
+                &db.timezones,
+                &db.feature_codes,
+                &db.populations,
+            ]
+            .into_iter()
+            .map(allocated)
+            .sum::<usize>()
+            + allocated(&db.modified)
+            + allocated(&db.name_offsets)
+            + allocated(&db.names);
+        let grid_bytes =
+            allocated(&db.grid.keys) + allocated(&db.grid.offsets) + allocated(&db.grid.indices);
+
+        DatabaseStats {
+            place_count: self.db.len(),
+            string_count: self.db.strings.len(),
+            grid_cell_count: self.db.grid.len(),
+            countries: self.countries(),
+            approx_memory_bytes: strings_bytes + places_bytes + grid_bytes,
+        }
+    }
+
+    /// Returns the build metadata from the database header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let info = Geocoder::global().database_info();
+    /// if let Some(built_at) = info.built_at {
+    ///     println!("{} places, built {}", info.place_count, built_at);
+    /// }
+    /// # }
+    /// ```
+    pub fn database_info(&self) -> DatabaseInfo {
+        self.db.info()
+    }
+
+    /// Compares this geocoder's database with a newer one, for example before shipping a
+    /// rebuild from a fresh GeoNames snapshot.
+    ///
+    /// See [`Database::diff()`] for how places are matched. Indices in the result can be
+    /// resolved with [`Geocoder::place_at()`] on `self` (removed, `old_index`) and on
+    /// `other` (added, `new_index`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), genom::DatabaseError> {
+    /// use genom::Geocoder;
+    ///
+    /// let old = Geocoder::from_path("places-old.bin")?;
+    /// let new = Geocoder::from_path("places-new.bin")?;
+    /// let diff = old.diff(&new, 10.0);
+    /// println!("{} added, {} removed", diff.added.len(), diff.removed.len());
+    /// for moved in &diff.moved {
+    ///     let place = new.place_at(moved.new_index).unwrap();
+    ///     println!("{} moved {:.0} km", place.city, moved.distance_km);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &Geocoder, threshold_km: f64) -> DatabaseDiff {
+        self.db.diff(&other.db, threshold_km)
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code of the country the nearest place belongs to.
+    ///
+    /// Cheaper than `lookup(...).map(|p| p.country_code)`: only the country code is
+    /// resolved and no enrichment runs. The search expands ring by ring up to 30 cells
+    /// (~330 km at the equator), so coordinates off the coast that [`Geocoder::lookup()`]
+    /// misses still resolve to the nearby country. Near a border the result is the
+    /// country of the nearest place, which is not always the country containing the
+    /// coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let geocoder = Geocoder::global();
+    /// assert_eq!(geocoder.country_of(48.8566, 2.3522).as_deref(), Some("FR"));
+    ///
+    /// // In the Bay of Biscay, 100 km off the French coast
+    /// assert_eq!(geocoder.country_of(46.0, -2.5).as_deref(), Some("FR"));
+    /// # }
+    /// ```
+    pub fn country_of(&self, latitude: f64, longitude: f64) -> Option<String> {
+        let location = Location::new(latitude, longitude);
+        let grid_key = self.grid_key(&location);
+        let idx = self.find_nearest_matching(
+            &location,
+            grid_key,
+            COUNTRY_SEARCH_RING,
+            |_| true,
+            &mut LookupDebug::default(),
+        )?;
+        Some(self.db.strings[self.db.country_codes[idx] as usize].clone())
+    }
+
+    /// Returns the distinct ISO 3166-1 alpha-2 country codes present in the database,
+    /// sorted alphabetically.
+    ///
+    /// The list is computed on first call and cached for the lifetime of the geocoder.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// for code in Geocoder::global().countries() {
+    ///     println!("{}", code);
+    /// }
+    /// # }
+    /// ```
+    pub fn countries(&self) -> Vec<String> {
+        self.countries
+            .get_or_init(|| self.distinct_strings(&self.db.country_codes))
+            .clone()
+    }
+
+    /// Returns the distinct IANA timezone identifiers present in the database, sorted
+    /// alphabetically.
+    ///
+    /// The list is computed on first call and cached for the lifetime of the geocoder.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let timezones = Geocoder::global().timezones();
+    /// assert!(timezones.iter().any(|tz| tz == "Europe/Berlin"));
+    /// # }
+    /// ```
+    pub fn timezones(&self) -> Vec<String> {
+        self.timezones
+            .get_or_init(|| self.distinct_strings(&self.db.timezones))
+            .clone()
+    }
+
+    /// Returns the extent of the database as `(min_lat, min_lon, max_lat, max_lon)`, the
+    /// same order as `GENOM_BOUNDING_BOX`.
+    ///
+    /// Useful to fit a map to the covered area, especially for regional or sharded
+    /// builds. The box is computed on first call and cached for the lifetime of the
+    /// geocoder. It is a plain minimum and maximum, so data on both sides of the
+    /// antimeridian spans nearly all longitudes. An empty database yields all zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let (min_lat, min_lon, max_lat, max_lon) = Geocoder::global().coverage_bbox();
+    /// assert!(min_lat <= max_lat && min_lon <= max_lon);
+    /// # }
+    /// ```
+    pub fn coverage_bbox(&self) -> (f64, f64, f64, f64) {
+        *self.coverage.get_or_init(|| {
+            let range = |column: &[i32]| {
+                let min = column.iter().copied().min().unwrap_or(0);
+                let max = column.iter().copied().max().unwrap_or(0);
+                (min as f64 / 100000.0, max as f64 / 100000.0)
+            };
+            let (min_lat, max_lat) = range(&self.db.lats);
+            let (min_lon, max_lon) = range(&self.db.lons);
+            (min_lat, min_lon, max_lat, max_lon)
+        })
+    }
+
+    /// Returns every place in the given country, ordered by city name.
+    ///
+    /// The country code comparison is case-insensitive. It is resolved to string table
+    /// indices once, so the scan over the places compares integers rather than
+    /// strings. Names are ordered ignoring case and diacritics. Returns an empty vector
+    /// for a country without places.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// let places = Geocoder::global().places_in_country("IS");
+    /// assert!(places.iter().any(|p| p.city == "Reykjavík"));
+    /// # }
+    /// ```
+    pub fn places_in_country(&self, country_code: &str) -> Vec<Place> {
+        let country_code = country_code.trim();
+        let codes: Vec<u32> = self
+            .db
+            .strings
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.eq_ignore_ascii_case(country_code))
+            .map(|(idx, _)| idx as u32)
+            .collect();
+        if codes.is_empty() {
+            return Vec::new();
+        }
+
+        let mut places: Vec<(String, usize)> = self
+            .db
+            .country_codes
+            .iter()
+            .enumerate()
+            .filter(|(_, code)| codes.contains(code))
+            .map(|(idx, _)| {
+                let city = &self.db.strings[self.db.cities[idx] as usize];
+                (normalize_name(city), idx)
+            })
+            .collect();
+        places.sort_unstable();
+        places
+            .into_iter()
+            .map(|(_, idx)| self.build_place(idx))
+            .collect()
+    }
+
+    /// Finds all places with the given postal code in the given country.
+    ///
+    /// Postal codes are not unique to one place, so every match is returned, in database
+    /// order. Matching ignores case, whitespace, and hyphens, so `"sw1a 1aa"` matches
+    /// `"SW1A1AA"` and `"1000001"` matches `"100-0001"`. The country code comparison is
+    /// case-insensitive.
+    ///
+    /// The supporting index is built on first call and cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// for place in Geocoder::global().lookup_postal("FR", "75001") {
+    ///     println!("{} ({}, {})", place.city, place.latitude, place.longitude);
+    /// }
+    /// # }
+    /// ```
+    pub fn lookup_postal(&self, country_code: &str, postal_code: &str) -> Vec<Place> {
+        let index = self.postal_index.get_or_init(|| {
+            let mut index: FxHashMap<String, Vec<u32>> = FxHashMap::default();
+            for (idx, &postal) in self.db.postal_codes.iter().enumerate() {
+                let postal = &self.db.strings[postal as usize];
+                if !postal.is_empty() {
+                    index
+                        .entry(normalize_postal_code(postal))
+                        .or_default()
+                        .push(idx as u32);
+                }
+            }
+            index
+        });
+
+        index
+            .get(&normalize_postal_code(postal_code))
+            .into_iter()
+            .flatten()
+            .map(|&idx| idx as usize)
+            .filter(|&idx| {
+                self.db.strings[self.db.country_codes[idx] as usize]
+                    .eq_ignore_ascii_case(country_code.trim())
+            })
+            .map(|idx| self.build_place(idx))
+            .collect()
+    }
+
+    /// Suggests places whose city name starts with `prefix`, ignoring case and diacritics.
+    ///
+    /// Returns at most `limit` places ordered alphabetically by normalized city name. An
+    /// empty prefix returns no suggestions. Both the prefix and the names are compared
+    /// in their [`normalize_name`] form, so `"zur"` suggests "Zürich".
+    ///
+    /// The supporting index (every city name, normalized and sorted) is built on first
+    /// call and cached, which costs one allocation per place.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// for place in Geocoder::global().autocomplete("ber", 5) {
+    ///     println!("{}, {}", place.city, place.country_name);
+    /// }
+    /// # }
+    /// ```
+    pub fn autocomplete(&self, prefix: &str, limit: usize) -> Vec<Place> {
+        let prefix = normalize_name(prefix.trim());
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+
+        let index = self.name_index();
+        let start = index.partition_point(|(name, _)| name.as_str() < prefix.as_str());
+        index[start..]
+            .iter()
+            .take_while(|(name, _)| name.starts_with(&prefix))
+            .take(limit)
+            .map(|&(_, idx)| self.build_place(idx as usize))
+            .collect()
+    }
+
+    /// Finds places whose city name is within `max_distance` edits of `query`.
+    ///
+    /// Uses Levenshtein distance (insertions, deletions, substitutions) over names in
+    /// their [`normalize_name`] form, so misspellings like `"Pheonix"` or `"Mumbay"` and
+    /// missing accents like `"Zurich"` still match. Returns at most
+    /// `limit` places paired with their edit distance, sorted by distance and then
+    /// alphabetically by city name.
+    ///
+    /// Names whose length differs from the query by more than `max_distance` are skipped
+    /// without computing the distance, and the distance computation stops early once it
+    /// cannot stay within `max_distance`. Each distinct name is only compared once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Geocoder;
+    ///
+    /// for (place, distance) in Geocoder::global().fuzzy_search("Pheonix", 2, 5) {
+    ///     println!("{} ({} edits)", place.city, distance);
+    /// }
+    /// # }
+    /// ```
+    pub fn fuzzy_search(
+        &self,
+        query: &str,
+        max_distance: usize,
+        limit: usize,
+    ) -> Vec<(Place, usize)> {
+        let query: Vec<char> = normalize_name(query.trim()).chars().collect();
+        if query.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(usize, u32)> = Vec::new();
+        let mut previous: Option<(&str, Option<usize>)> = None;
+        for (name, idx) in self.name_index() {
+            let distance = match previous {
+                Some((prev, distance)) if prev == name => distance,
+                _ => {
+                    let name_len = name.chars().count();
+                    let distance = if name_len.abs_diff(query.len()) > max_distance {
+                        None
+                    } else {
+                        bounded_levenshtein(&query, name, max_distance)
+                    };
+                    previous = Some((name, distance));
+                    distance
+                }
+            };
+            if let Some(distance) = distance {
+                matches.push((distance, *idx));
+            }
+        }
+
+        matches.sort_by_key(|&(distance, _)| distance);
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(distance, idx)| (self.build_place(idx as usize), distance))
+            .collect()
+    }
+
+    fn name_index(&self) -> &[(String, u32)] {
+        self.name_index.get_or_init(|| {
+            let mut index: Vec<(String, u32)> = self
+                .db
+                .cities
+                .iter()
+                .enumerate()
+                .map(|(idx, &city)| (normalize_name(&self.db.strings[city as usize]), idx as u32))
+                .collect();
+            index.sort_unstable();
+            index
+        })
+    }
+
+    fn distinct_strings(&self, column: &[u32]) -> Vec<String> {
+        let indices: rustc_hash::FxHashSet<u32> = column.iter().copied().collect();
+        let mut values: Vec<String> = indices
+            .into_iter()
+            .map(|idx| self.db.strings[idx as usize].clone())
+            .filter(|s| !s.is_empty())
+            .collect();
+        values.sort_unstable();
+        values
+    }
+
+    pub(crate) fn grid_key(&self, location: &Location) -> (i16, i16) {
+        (
+            ((location.latitude * 100000.0) as i32 / 10000) as i16,
+            ((location.longitude * 100000.0) as i32 / 10000) as i16,
+        )
+    }
+
+    fn find_nearest(&self, location: &Location, grid_key: (i16, i16)) -> Option<usize> {
+        self.find_nearest_traced(location, grid_key, &mut LookupDebug::default())
+    }
+
+    /// Searches the 3×3 cell neighborhood of `grid_key`, recording statistics in `debug`.
+    fn find_nearest_traced(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        debug: &mut LookupDebug,
+    ) -> Option<usize> {
+        let mut best = None;
+        for ring in 0..=1 {
+            #[cfg(feature = "simd")]
+            if self.vectorize {
+                self.scan_ring_vectorized(location, grid_key, ring, &mut best, debug);
+                continue;
+            }
+            self.scan_ring(location, grid_key, ring, |_| true, &mut best, debug);
+        }
+        debug.nearest_distance_km = best.map(|(_, distance)| distance);
+        debug.place_index = best.map(|(idx, _)| idx);
+        best.map(|(idx, _)| idx)
+    }
+
+    /// Finds the nearest place accepted by `matches`, expanding the search ring by ring.
+    ///
+    /// Finding a match in ring `r` does not end the search: a place in a later ring can
+    /// still be closer, since the nearest cell is not necessarily the one holding the
+    /// nearest place. The search stops once the best distance is below the smallest
+    /// possible distance to any cell outside the rings searched so far (and always
+    /// covers at least the 3×3 neighborhood), so the result is the nearest match within
+    /// `max_ring` cells. See [`Geocoder::can_stop`] for metrics without such a bound.
+    fn find_nearest_matching(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        max_ring: i16,
+        matches: impl Fn(usize) -> bool,
+        debug: &mut LookupDebug,
+    ) -> Option<usize> {
+        let mut best = None;
+        let mut last_ring = 0;
+
+        for ring in 0..=max_ring {
+            self.scan_ring(location, grid_key, ring, &matches, &mut best, debug);
+            last_ring = ring;
+            if best.is_some_and(|(_, nearest)| self.can_stop(location, ring, nearest)) {
+                break;
+            }
+        }
+
+        debug.rings_expanded = (last_ring as usize).saturating_sub(1);
+        debug.nearest_distance_km = best.map(|(_, distance)| distance);
+        debug.place_index = best.map(|(idx, _)| idx);
+        best.map(|(idx, _)| idx)
+    }
+
+    /// Updates `best` with the nearest place accepted by `matches` in one ring of cells.
+    fn scan_ring(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        ring: i16,
+        matches: impl Fn(usize) -> bool,
+        best: &mut Option<(usize, f64)>,
+        debug: &mut LookupDebug,
+    ) {
+        for key in ring_cells(grid_key, ring) {
+            debug.grid_cells_searched += 1;
+            for &idx in self.db.grid.get(key).into_iter().flatten() {
+                let idx = idx as usize;
+                if !matches(idx) {
+                    continue;
+                }
+                debug.candidates_checked += 1;
+                let distance = self.metric.distance(location, &self.db.location(idx));
+                if best.is_none_or(|nearest| closer((idx, distance), nearest)) {
+                    *best = Some((idx, distance));
+                }
+            }
+        }
+    }
+
+    /// Like [`Geocoder::scan_ring`] without a filter, but ranks cells with at least
+    /// [`simd::MIN_CANDIDATES`] places by the vectorized scan.
+    ///
+    /// The scan ranks in `f32`, so of two candidates less than about a meter apart in
+    /// distance it may pick a different one than the scalar loop would.
+    #[cfg(feature = "simd")]
+    fn scan_ring_vectorized(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        ring: i16,
+        best: &mut Option<(usize, f64)>,
+        debug: &mut LookupDebug,
+    ) {
+        for key in ring_cells(grid_key, ring) {
+            debug.grid_cells_searched += 1;
+            let Some(indices) = self.db.grid.get(key) else {
+                continue;
+            };
+            debug.candidates_checked += indices.len();
+            if indices.len() < crate::simd::MIN_CANDIDATES {
+                for &idx in indices {
+                    let idx = idx as usize;
+                    let distance = self.metric.distance(location, &self.db.location(idx));
+                    if best.is_none_or(|nearest| closer((idx, distance), nearest)) {
+                        *best = Some((idx, distance));
+                    }
+                }
+                continue;
+            }
+            if let Some(position) = crate::simd::nearest_in_cell(&self.db, indices, location) {
+                let idx = indices[position] as usize;
+                let distance = self.metric.distance(location, &self.db.location(idx));
+                if best.is_none_or(|nearest| closer((idx, distance), nearest)) {
+                    *best = Some((idx, distance));
+                }
+            }
+        }
+    }
+
+    /// Returns every place within `radius_km` of `location` as `(index, distance)` pairs
+    /// sorted by distance, searching rings until no unsearched cell can hold one.
+    fn find_within(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        radius_km: f64,
+        max_ring: i16,
+    ) -> Vec<(usize, f64)> {
+        let mut found = Vec::new();
+        for ring in 0..=max_ring {
+            for key in ring_cells(grid_key, ring) {
+                for &idx in self.db.grid.get(key).into_iter().flatten() {
+                    let idx = idx as usize;
+                    let distance = self.metric.distance(location, &self.db.location(idx));
+                    if distance <= radius_km {
+                        found.push((idx, distance));
+                    }
+                }
+            }
+            if self.can_stop(location, ring, radius_km) {
+                break;
+            }
+        }
+        found.sort_unstable_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        found
+    }
+
+    /// Returns the `k` places nearest to `location` as `(index, distance)` pairs sorted
+    /// by distance, expanding rings until no unsearched cell can hold a closer place.
+    fn find_k_nearest(
+        &self,
+        location: &Location,
+        grid_key: (i16, i16),
+        k: usize,
+        max_ring: i16,
+    ) -> Vec<(usize, f64)> {
+        let mut nearest: Vec<(usize, f64)> = Vec::new();
+        if k == 0 {
+            return nearest;
+        }
+
+        for ring in 0..=max_ring {
+            for key in ring_cells(grid_key, ring) {
+                for &idx in self.db.grid.get(key).into_iter().flatten() {
+                    let idx = idx as usize;
+                    let distance = self.metric.distance(location, &self.db.location(idx));
+                    if nearest.len() == k && !closer((idx, distance), nearest[k - 1]) {
+                        continue;
+                    }
+                    let position = nearest.partition_point(|&other| closer(other, (idx, distance)));
+                    nearest.insert(position, (idx, distance));
+                    nearest.truncate(k);
+                }
+            }
+            if nearest.len() == k && self.can_stop(location, ring, nearest[k - 1].1) {
+                break;
+            }
+        }
+
+        nearest
+    }
+
+    /// Returns whether a ring search that has covered `ring` rings can stop, because no
+    /// unsearched cell can hold a place within `distance` of `location`.
+    ///
+    /// The bound is in great-circle kilometers, so searches with a custom metric never
+    /// stop early.
+    fn can_stop(&self, location: &Location, ring: i16, distance: f64) -> bool {
+        self.early_stop && distance < unsearched_distance_bound(location, ring)
+    }
+
+    fn build_place(&self, idx: usize) -> Place {
+        enrich_place(self.place_input(idx))
+    }
+
+    fn place_input(&self, idx: usize) -> PlaceInput<'_> {
+        let place = self.db.place(idx);
+        PlaceInput {
+            city: &self.db.strings[place.city as usize],
+            city_ascii: &self.db.strings[place.city_ascii as usize],
+            region: &self.db.strings[place.region as usize],
+            region_code: &self.db.strings[place.region_code as usize],
+            district: &self.db.strings[place.district as usize],
+            subdistrict: &self.db.strings[place.subdistrict as usize],
+            country_code: &self.db.strings[place.country_code as usize],
+            postal_code: &self.db.strings[place.postal_code as usize],
+            timezone: &self.db.strings[place.timezone as usize],
+            feature_code: &self.db.strings[place.feature_code as usize],
+            modified: place.modified_date(),
+            latitude: place.lat as f64 / 100000.0,
+            longitude: place.lon as f64 / 100000.0,
+            alternate_names: AlternateNames::new(&self.db.strings, self.db.names(idx)),
+        }
+    }
+}
+
+/// Normalizes a place name for matching: lowercases it and strips diacritics.
+///
+/// The name is decomposed (Unicode NFD) and combining marks are dropped, so "Zürich",
+/// "ZURICH" and "zurich" all normalize to `"zurich"`. Letters without a decomposition,
+/// such as "ß" or "ø", are only lowercased. This is the form used by
+/// [`Geocoder::autocomplete`] and [`Geocoder::fuzzy_search`]; apply it to your own data
+/// to match the crate's behavior.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(genom::normalize_name("Zürich"), "zurich");
+/// assert_eq!(genom::normalize_name("São Paulo"), "sao paulo");
+/// ```
+pub fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .nfd()
+        .filter(|&c| !is_combining_mark(c))
+        .collect()
+}
+
+/// Leading bytes of a zstd frame, identifying a compressed database.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// First four bytes of the six-byte xz stream header (`FD 37 7A 58 5A 00`).
+const XZ_MAGIC: [u8; 4] = [0xFD, 0x37, 0x7A, 0x58];
+
+/// Name of the index file in a shard directory, listing one `CC<TAB>places` line per shard.
+const SHARD_INDEX: &str = "index.tsv";
+
+/// Maximum ring of grid cells searched around the query cell by filtered lookups.
+const MAX_SEARCH_RING: i16 = 10;
+
+/// Extra distance in kilometers [`Geocoder::lookup_prefer_postal()`] accepts to return
+/// a place with a postal code instead of the nearest place.
+const POSTAL_TIE_KM: f64 = 0.25;
+
+/// Maximum ring searched by [`Geocoder::country_of()`]. Countries are large, so a
+/// nearest place up to ~330 km away still identifies the right one for most offshore
+/// coordinates.
+const COUNTRY_SEARCH_RING: i16 = 30;
+
+/// Largest useful search ring. Grid keys span -900..=900 in latitude and -1800..=1800 in
+/// longitude, so 1800 rings reach every cell from any query cell.
+const MAX_GRID_RING: i16 = 1800;
+
+/// Maximum ring searched by [`Geocoder::lookup_min_population()`], ~550 km. Cities above
+/// a population threshold can be far apart outside densely settled regions.
+const POPULATION_SEARCH_RING: i16 = 50;
+
+/// Returns a lower bound in kilometers on the distance from `location` to any place
+/// outside the `ring`-cell neighborhood of its grid cell.
+///
+/// Every grid cell spans at least 0.1° in each direction, so such a place differs from
+/// `location` by at least `ring * 0.1°` in latitude or in longitude. The bound is the
+/// smaller of the meridian arc for the latitude offset and the cross-track distance to
+/// the meridian at the longitude offset, both on the 6371 km sphere, less a 1% margin.
+/// [`Equirectangular`] never returns less than the great-circle distance and
+/// [`Ellipsoidal`] stays within 0.6% of it at any range, so the margin covers every
+/// built-in metric for all rings up to [`MAX_GRID_RING`].
+fn unsearched_distance_bound(location: &Location, ring: i16) -> f64 {
+    let offset = (f64::from(ring) * 0.1).to_radians();
+    let latitude = location.latitude.to_radians();
+    let meridian_arc = offset;
+    let cross_track = (latitude.cos() * offset.sin()).asin();
+    6371.0 * meridian_arc.min(cross_track) * 0.99
+}
+
+/// Checks that grid keys are strictly ascending. Lookups binary-search the keys, so a
+/// repeated or out-of-order cell would hide its places instead of failing loudly.
+fn check_grid_keys(keys: impl Iterator<Item = (i16, i16)> + Clone) -> Result<(), DatabaseError> {
+    for (previous, key) in keys.clone().zip(keys.skip(1)) {
+        match previous.cmp(&key) {
+            std::cmp::Ordering::Less => {}
+            std::cmp::Ordering::Equal => return Err(DatabaseError::DuplicateGridKey(previous)),
+            std::cmp::Ordering::Greater => return Err(DatabaseError::UnsortedGrid),
+        }
+    }
+    Ok(())
+}
+
+/// Splits `count` values of `width` bytes off the front of `data`.
+fn take<'a>(data: &mut &'a [u8], count: usize, width: usize) -> Result<&'a [u8], DatabaseError> {
+    let len = count.checked_mul(width).ok_or(DatabaseError::Truncated)?;
+    let (section, rest) = data.split_at_checked(len).ok_or(DatabaseError::Truncated)?;
+    *data = rest;
+    Ok(section)
+}
+
+fn take_array<const N: usize>(data: &mut &[u8]) -> Result<[u8; N], DatabaseError> {
+    Ok(take(data, N, 1)?.try_into().unwrap())
+}
+
+/// Returns the last little-endian `u32` of a non-empty offset column.
+fn last_u32(column: &[u8]) -> usize {
+    u32::from_le_bytes(column[column.len() - 4..].try_into().unwrap()) as usize
+}
+
+/// Decodes a section of little-endian `u32` values.
+fn le_u32s(section: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    section
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+}
+
+/// Checks that every index is below `len`.
+fn check_indices(indices: impl Iterator<Item = u32>, len: usize) -> Result<(), DatabaseError> {
+    for idx in indices {
+        if idx as usize >= len {
+            return Err(DatabaseError::IndexOutOfRange);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that an offset column never decreases. Its last entry is the length of the
+/// section it indexes, so every range it describes is then in bounds.
+fn check_offsets(offsets: impl Iterator<Item = u32>) -> Result<(), DatabaseError> {
+    let mut previous = 0;
+    for offset in offsets {
+        if offset < previous {
+            return Err(DatabaseError::IndexOutOfRange);
+        }
+        previous = offset;
+    }
+    Ok(())
+}
+
+/// Orders `(index, distance)` candidates by distance, breaking exact ties in favor of the
+/// lower database index so that results do not depend on the order cells are scanned in.
+fn closer(candidate: (usize, f64), other: (usize, f64)) -> bool {
+    candidate.1 < other.1 || (candidate.1 == other.1 && candidate.0 < other.0)
+}
+
+/// Returns the keys of the grid cells exactly `ring` cells away from `center`
+/// (Chebyshev distance). Ring 0 is the center cell itself.
+///
+/// Only the four edges of the ring are generated, so a ring costs O(`ring`) cells. Keys
+/// are computed in `i32` and cells beyond the `i16` key range are skipped.
+fn ring_cells(center: (i16, i16), ring: i16) -> impl Iterator<Item = (i16, i16)> {
+    let (lat, lon, ring) = (i32::from(center.0), i32::from(center.1), i32::from(ring));
+    let rows = (-ring..=ring).flat_map(move |dlon| {
+        let top = (ring > 0).then_some((lat + ring, lon + dlon));
+        std::iter::once((lat - ring, lon + dlon)).chain(top)
+    });
+    let columns =
+        (1 - ring..ring).flat_map(move |dlat| [(lat + dlat, lon - ring), (lat + dlat, lon + ring)]);
+    rows.chain(columns)
+        .filter_map(|(lat, lon)| Some((i16::try_from(lat).ok()?, i16::try_from(lon).ok()?)))
+}
+
+fn normalize_postal_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Computes the Levenshtein distance between `a` and `b`, or `None` if it exceeds `max`.
+fn bounded_levenshtein(a: &[char], b: &str, max: usize) -> Option<usize> {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        let mut row_min = row[0];
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+            row_min = row_min.min(row[j + 1]);
+        }
+        if row_min > max {
+            return None;
+        }
+    }
+
+    Some(row[b.len()]).filter(|&d| d <= max)
 }
@@ -0,0 +1,181 @@
+//! Parsing and formatting of degrees/minutes/seconds coordinates.
+//!
+//! The parser accepts the notations found in legacy data and user input:
+//! `48°51'24"N 2°21'8"E`, `N 48° 51.4' E 2° 21.13'`, `48 51 24 N, 2 21 8 E`,
+//! `-33.8688, 151.2093` and mixtures thereof. Degree, minute and second marks may be
+//! the Unicode symbols (`°`, `º`, `′`, `″`, `’`, `”`) or ASCII substitutes (`'`, `"`,
+//! `''`). Components without a mark are assigned positionally.
+
+/// One coordinate being assembled from degree, minute and second components.
+#[derive(Default)]
+struct Component {
+    /// Degrees, minutes and seconds, indexed by slot
+    values: [f64; 3],
+    /// Next free slot, or 0 if no number has been read
+    slots_used: usize,
+    /// Whether the last number read had a fractional part (only the last may)
+    fractional: bool,
+    /// Whether the degrees carried a minus sign
+    negative: bool,
+    /// Hemisphere letter, upper-cased
+    hemisphere: Option<char>,
+}
+
+impl Component {
+    fn is_empty(&self) -> bool {
+        self.slots_used == 0 && self.hemisphere.is_none()
+    }
+
+    /// Returns the signed decimal degrees and the hemisphere letter, if valid.
+    fn finish(&self) -> Option<(f64, Option<char>)> {
+        if self.slots_used == 0 {
+            return None;
+        }
+        let [degrees, minutes, seconds] = self.values;
+        if !(0.0..60.0).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+            return None;
+        }
+        let value = degrees + minutes / 60.0 + seconds / 3600.0;
+        match self.hemisphere {
+            Some('S' | 'W') if self.negative => None,
+            Some('S' | 'W') => Some((-value, self.hemisphere)),
+            _ if self.negative => Some((-value, self.hemisphere)),
+            _ => Some((value, self.hemisphere)),
+        }
+    }
+}
+
+/// Parses a DMS or decimal coordinate pair into `(latitude, longitude)`.
+pub(crate) fn parse(s: &str) -> Option<(f64, f64)> {
+    let chars: Vec<char> = s.trim().chars().collect();
+    let prefix_hemispheres = chars.first().is_some_and(|c| is_hemisphere(*c));
+
+    let mut components: Vec<(f64, Option<char>)> = Vec::new();
+    let mut current = Component::default();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() || c == '.' || c == '-' || c == '+' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let value: f64 = text.parse().ok()?;
+
+            // An explicit mark right after the number overrides positional assignment
+            let mut j = i;
+            while j < chars.len() && chars[j] == ' ' {
+                j += 1;
+            }
+            let (marked, mark_len) = match chars.get(j) {
+                Some('°' | 'º') => (Some(0), 1),
+                Some('\'') if chars.get(j + 1) == Some(&'\'') => (Some(2), 2),
+                Some('\'' | '′' | '’') => (Some(1), 1),
+                Some('"' | '″' | '”') => (Some(2), 1),
+                _ => (None, 0),
+            };
+            if marked.is_some() {
+                i = j + mark_len;
+            }
+
+            // A new coordinate starts at a repeated or earlier slot, or after a number
+            // with a fractional part (which must be the last of its coordinate)
+            let restarts = current.slots_used > 0
+                && (current.fractional || marked.is_some_and(|slot| slot < current.slots_used));
+            if restarts {
+                // With leading hemisphere letters, only a letter starts a coordinate
+                if prefix_hemispheres {
+                    return None;
+                }
+                components.push(current.finish()?);
+                current = Component::default();
+            }
+            let slot = marked.unwrap_or(current.slots_used);
+            if slot > 2 || slot < current.slots_used {
+                return None;
+            }
+            if value.is_sign_negative() || text.starts_with('+') {
+                if current.slots_used > 0 {
+                    return None;
+                }
+                current.negative = value.is_sign_negative();
+            }
+            current.values[slot] = value.abs();
+            current.slots_used = slot + 1;
+            current.fractional = text.contains('.');
+            continue;
+        }
+
+        if is_hemisphere(c) {
+            let hemisphere = c.to_ascii_uppercase();
+            if prefix_hemispheres {
+                if !current.is_empty() {
+                    components.push(current.finish()?);
+                    current = Component::default();
+                }
+                current.hemisphere = Some(hemisphere);
+            } else {
+                if current.slots_used == 0 || current.hemisphere.is_some() {
+                    return None;
+                }
+                current.hemisphere = Some(hemisphere);
+                components.push(current.finish()?);
+                current = Component::default();
+            }
+        } else if c == ',' || c == ';' {
+            if !current.is_empty() {
+                components.push(current.finish()?);
+                current = Component::default();
+            }
+        } else if !(c.is_whitespace() || matches!(c, '°' | 'º' | ':')) {
+            return None;
+        }
+        i += 1;
+    }
+    if !current.is_empty() {
+        components.push(current.finish()?);
+    }
+
+    let [(first, first_hemisphere), (second, second_hemisphere)] = components[..] else {
+        return None;
+    };
+    let is_latitude = |h: Option<char>| h.map(|h| h == 'N' || h == 'S');
+    let (latitude, longitude) = match (
+        is_latitude(first_hemisphere),
+        is_latitude(second_hemisphere),
+    ) {
+        (Some(true), Some(true)) | (Some(false), Some(false)) => return None,
+        (Some(false), _) | (None, Some(true)) => (second, first),
+        _ => (first, second),
+    };
+    ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude))
+        .then_some((latitude, longitude))
+}
+
+/// Formats a coordinate pair as `48°51'23.8"N 2°21'7.9"E`, with seconds to 0.1".
+pub(crate) fn format(latitude: f64, longitude: f64) -> String {
+    format!(
+        "{} {}",
+        format_component(latitude, if latitude < 0.0 { 'S' } else { 'N' }),
+        format_component(longitude, if longitude < 0.0 { 'W' } else { 'E' })
+    )
+}
+
+fn format_component(value: f64, hemisphere: char) -> String {
+    // Round once in tenths of a second so that 59.96" carries into the minutes
+    let tenths = (value.abs() * 36_000.0).round() as u64;
+    format!(
+        "{}°{}'{}.{}\"{}",
+        tenths / 36_000,
+        tenths / 600 % 60,
+        tenths / 10 % 60,
+        tenths % 10,
+        hemisphere
+    )
+}
+
+fn is_hemisphere(c: char) -> bool {
+    matches!(c.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W')
+}
@@ -12,9 +12,9 @@
 
 #![warn(missing_docs)]
 
-use crate::types::Place;
-use chrono::{Offset, TimeZone, Utc};
-use chrono_tz::Tz;
+use crate::types::{AlternateNames, Place, PlaceRef};
+use chrono::{DateTime, NaiveDate, Offset, TimeDelta, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, Tz};
 use rustc_hash::FxHashMap;
 use std::str::FromStr;
 use std::sync::LazyLock;
@@ -22,6 +22,7 @@ use std::sync::LazyLock;
 static COUNTRY_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
     [
         ("AF", "Afghanistan"),
+        ("AX", "Åland Islands"),
         ("AL", "Albania"),
         ("DZ", "Algeria"),
         ("AS", "American Samoa"),
@@ -109,6 +110,7 @@ static COUNTRY_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock
         ("GP", "Guadeloupe"),
         ("GU", "Guam"),
         ("GT", "Guatemala"),
+        ("GG", "Guernsey"),
         ("GN", "Guinea"),
         ("GW", "Guinea-Bissau"),
         ("GY", "Guyana"),
@@ -123,11 +125,13 @@ static COUNTRY_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock
         ("IR", "Iran"),
         ("IQ", "Iraq"),
         ("IE", "Ireland"),
+        ("IM", "Isle of Man"),
         ("IL", "Israel"),
         ("IT", "Italy"),
         ("CI", "Ivory Coast"),
         ("JM", "Jamaica"),
         ("JP", "Japan"),
+        ("JE", "Jersey"),
         ("JO", "Jordan"),
         ("KZ", "Kazakhstan"),
         ("KE", "Kenya"),
@@ -270,6 +274,7 @@ static COUNTRY_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock
 static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
     [
         ("AF", "AFN"),
+        ("AX", "EUR"),
         ("AL", "ALL"),
         ("DZ", "DZD"),
         ("AS", "USD"),
@@ -357,6 +362,7 @@ static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("GP", "EUR"),
         ("GU", "USD"),
         ("GT", "GTQ"),
+        ("GG", "GBP"),
         ("GN", "GNF"),
         ("GW", "XOF"),
         ("GY", "GYD"),
@@ -371,11 +377,13 @@ static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("IR", "IRR"),
         ("IQ", "IQD"),
         ("IE", "EUR"),
+        ("IM", "GBP"),
         ("IL", "ILS"),
         ("IT", "EUR"),
         ("CI", "XOF"),
         ("JM", "JMD"),
         ("JP", "JPY"),
+        ("JE", "GBP"),
         ("JO", "JOD"),
         ("KZ", "KZT"),
         ("KE", "KES"),
@@ -409,6 +417,7 @@ static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("MD", "MDL"),
         ("MC", "EUR"),
         ("MN", "MNT"),
+        ("ME", "EUR"),
         ("MS", "XCD"),
         ("MA", "MAD"),
         ("MZ", "MZN"),
@@ -457,6 +466,7 @@ static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("ST", "STN"),
         ("SA", "SAR"),
         ("SN", "XOF"),
+        ("RS", "RSD"),
         ("CS", "RSD"),
         ("SC", "SCR"),
         ("SL", "SLL"),
@@ -513,6 +523,187 @@ static COUNTRY_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
     .collect()
 });
 
+/// Countries with more than one official currency, primary (as in `COUNTRY_CURRENCIES`)
+/// first. Countries not listed here use only their primary currency.
+static COUNTRY_MULTIPLE_CURRENCIES: LazyLock<FxHashMap<&'static str, &'static [&'static str]>> =
+    LazyLock::new(|| {
+        [
+            ("BT", &["BTN", "INR"][..]),
+            ("LR", &["LRD", "USD"]),
+            ("LS", &["LSL", "ZAR"]),
+            ("NA", &["NAD", "ZAR"]),
+            ("PA", &["PAB", "USD"]),
+            ("SV", &["SVC", "USD"]),
+            ("SZ", &["SZL", "ZAR"]),
+            ("ZW", &["ZWL", "USD"]),
+        ]
+        .into_iter()
+        .collect()
+    });
+
+/// ISO 4217 currency code to (symbol, minor unit digits).
+static CURRENCY_INFO: LazyLock<FxHashMap<&'static str, (&'static str, u8)>> = LazyLock::new(|| {
+    [
+        ("AED", ("د.إ", 2)),
+        ("AFN", ("؋", 2)),
+        ("ALL", ("L", 2)),
+        ("AMD", ("֏", 2)),
+        ("ANG", ("ƒ", 2)),
+        ("AOA", ("Kz", 2)),
+        ("ARS", ("$", 2)),
+        ("AUD", ("$", 2)),
+        ("AWG", ("ƒ", 2)),
+        ("AZN", ("₼", 2)),
+        ("BAM", ("KM", 2)),
+        ("BBD", ("$", 2)),
+        ("BDT", ("৳", 2)),
+        ("BGN", ("лв", 2)),
+        ("BHD", (".د.ب", 3)),
+        ("BIF", ("FBu", 0)),
+        ("BMD", ("$", 2)),
+        ("BND", ("$", 2)),
+        ("BOB", ("Bs", 2)),
+        ("BRL", ("R$", 2)),
+        ("BSD", ("$", 2)),
+        ("BTN", ("Nu.", 2)),
+        ("BWP", ("P", 2)),
+        ("BYR", ("Br", 0)),
+        ("BZD", ("$", 2)),
+        ("CAD", ("$", 2)),
+        ("CDF", ("FC", 2)),
+        ("CHF", ("CHF", 2)),
+        ("CLP", ("$", 0)),
+        ("CNY", ("¥", 2)),
+        ("COP", ("$", 2)),
+        ("CRC", ("₡", 2)),
+        ("CUP", ("$", 2)),
+        ("CVE", ("$", 2)),
+        ("CZK", ("Kč", 2)),
+        ("DJF", ("Fdj", 0)),
+        ("DKK", ("kr", 2)),
+        ("DOP", ("$", 2)),
+        ("DZD", ("د.ج", 2)),
+        ("EGP", ("E£", 2)),
+        ("ERN", ("Nfk", 2)),
+        ("ETB", ("Br", 2)),
+        ("EUR", ("€", 2)),
+        ("FJD", ("$", 2)),
+        ("FKP", ("£", 2)),
+        ("GBP", ("£", 2)),
+        ("GEL", ("₾", 2)),
+        ("GHS", ("₵", 2)),
+        ("GIP", ("£", 2)),
+        ("GMD", ("D", 2)),
+        ("GNF", ("FG", 0)),
+        ("GTQ", ("Q", 2)),
+        ("GYD", ("$", 2)),
+        ("HKD", ("$", 2)),
+        ("HNL", ("L", 2)),
+        ("HRK", ("kn", 2)),
+        ("HTG", ("G", 2)),
+        ("HUF", ("Ft", 2)),
+        ("IDR", ("Rp", 2)),
+        ("ILS", ("₪", 2)),
+        ("INR", ("₹", 2)),
+        ("IQD", ("ع.د", 3)),
+        ("IRR", ("﷼", 2)),
+        ("ISK", ("kr", 0)),
+        ("JMD", ("$", 2)),
+        ("JOD", ("د.ا", 3)),
+        ("JPY", ("¥", 0)),
+        ("KES", ("KSh", 2)),
+        ("KGS", ("сом", 2)),
+        ("KHR", ("៛", 2)),
+        ("KMF", ("CF", 0)),
+        ("KPW", ("₩", 2)),
+        ("KRW", ("₩", 0)),
+        ("KWD", ("د.ك", 3)),
+        ("KYD", ("$", 2)),
+        ("KZT", ("₸", 2)),
+        ("LAK", ("₭", 2)),
+        ("LBP", ("ل.ل", 2)),
+        ("LKR", ("Rs", 2)),
+        ("LRD", ("$", 2)),
+        ("LSL", ("L", 2)),
+        ("LYD", ("ل.د", 3)),
+        ("MAD", ("د.م.", 2)),
+        ("MDL", ("L", 2)),
+        ("MGA", ("Ar", 2)),
+        ("MKD", ("ден", 2)),
+        ("MMK", ("K", 2)),
+        ("MNT", ("₮", 2)),
+        ("MOP", ("MOP$", 2)),
+        ("MRU", ("UM", 2)),
+        ("MUR", ("₨", 2)),
+        ("MVR", ("Rf", 2)),
+        ("MWK", ("MK", 2)),
+        ("MXN", ("$", 2)),
+        ("MYR", ("RM", 2)),
+        ("MZN", ("MT", 2)),
+        ("NAD", ("$", 2)),
+        ("NGN", ("₦", 2)),
+        ("NIO", ("C$", 2)),
+        ("NOK", ("kr", 2)),
+        ("NPR", ("₨", 2)),
+        ("NZD", ("$", 2)),
+        ("OMR", ("ر.ع.", 3)),
+        ("PAB", ("B/.", 2)),
+        ("PEN", ("S/", 2)),
+        ("PGK", ("K", 2)),
+        ("PHP", ("₱", 2)),
+        ("PKR", ("₨", 2)),
+        ("PLN", ("zł", 2)),
+        ("PYG", ("₲", 0)),
+        ("QAR", ("ر.ق", 2)),
+        ("RON", ("lei", 2)),
+        ("RSD", ("дин.", 2)),
+        ("RUB", ("₽", 2)),
+        ("RWF", ("FRw", 0)),
+        ("SAR", ("ر.س", 2)),
+        ("SBD", ("$", 2)),
+        ("SCR", ("₨", 2)),
+        ("SDG", ("ج.س.", 2)),
+        ("SEK", ("kr", 2)),
+        ("SGD", ("$", 2)),
+        ("SHP", ("£", 2)),
+        ("SLL", ("Le", 2)),
+        ("SOS", ("Sh", 2)),
+        ("SRD", ("$", 2)),
+        ("STN", ("Db", 2)),
+        ("SVC", ("₡", 2)),
+        ("SYP", ("£S", 2)),
+        ("SZL", ("E", 2)),
+        ("THB", ("฿", 2)),
+        ("TJS", ("SM", 2)),
+        ("TMT", ("m", 2)),
+        ("TND", ("د.ت", 3)),
+        ("TOP", ("T$", 2)),
+        ("TRY", ("₺", 2)),
+        ("TTD", ("$", 2)),
+        ("TWD", ("NT$", 2)),
+        ("TZS", ("TSh", 2)),
+        ("UAH", ("₴", 2)),
+        ("UGX", ("USh", 0)),
+        ("USD", ("$", 2)),
+        ("UYU", ("$", 2)),
+        ("UZS", ("soʻm", 2)),
+        ("VES", ("Bs.", 2)),
+        ("VND", ("₫", 0)),
+        ("VUV", ("VT", 0)),
+        ("WST", ("WS$", 2)),
+        ("XAF", ("FCFA", 0)),
+        ("XCD", ("$", 2)),
+        ("XOF", ("CFA", 0)),
+        ("XPF", ("₣", 0)),
+        ("YER", ("﷼", 2)),
+        ("ZAR", ("R", 2)),
+        ("ZMW", ("ZK", 2)),
+        ("ZWL", ("$", 2)),
+    ]
+    .into_iter()
+    .collect()
+});
+
 static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
     [
         ("DZ", "AF"),
@@ -547,13 +738,16 @@ static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("ML", "AF"),
         ("MR", "AF"),
         ("MU", "AF"),
+        ("YT", "AF"),
         ("MA", "AF"),
         ("MZ", "AF"),
         ("NA", "AF"),
         ("NE", "AF"),
         ("NG", "AF"),
         ("CG", "AF"),
+        ("RE", "AF"),
         ("RW", "AF"),
+        ("SH", "AF"),
         ("ST", "AF"),
         ("SN", "AF"),
         ("SC", "AF"),
@@ -627,6 +821,7 @@ static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("UZ", "AS"),
         ("VN", "AS"),
         ("YE", "AS"),
+        ("AX", "EU"),
         ("AL", "EU"),
         ("AD", "EU"),
         ("AT", "EU"),
@@ -639,15 +834,19 @@ static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("CZ", "EU"),
         ("DK", "EU"),
         ("EE", "EU"),
+        ("FO", "EU"),
         ("FI", "EU"),
         ("FR", "EU"),
         ("DE", "EU"),
         ("GI", "EU"),
         ("GR", "EU"),
+        ("GG", "EU"),
         ("HU", "EU"),
         ("IS", "EU"),
         ("IE", "EU"),
+        ("IM", "EU"),
         ("IT", "EU"),
+        ("JE", "EU"),
         ("LV", "EU"),
         ("LI", "EU"),
         ("LT", "EU"),
@@ -677,10 +876,12 @@ static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("VA", "EU"),
         ("AI", "NA"),
         ("AG", "NA"),
+        ("AW", "NA"),
         ("BS", "NA"),
         ("BB", "NA"),
         ("BZ", "NA"),
         ("BM", "NA"),
+        ("VG", "NA"),
         ("CA", "NA"),
         ("KY", "NA"),
         ("CR", "NA"),
@@ -706,6 +907,7 @@ static COUNTRY_CONTINENTS: LazyLock<FxHashMap<&'static str, &'static str>> = Laz
         ("LC", "NA"),
         ("PM", "NA"),
         ("VC", "NA"),
+        ("TT", "NA"),
         ("TC", "NA"),
         ("US", "NA"),
         ("UM", "NA"),
@@ -768,6 +970,286 @@ static CONTINENT_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLo
     .collect()
 });
 
+static COUNTRY_SUBREGIONS: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    [
+        ("AF", "034"),
+        ("AL", "039"),
+        ("DZ", "015"),
+        ("AS", "061"),
+        ("AD", "039"),
+        ("AO", "017"),
+        ("AI", "029"),
+        ("AG", "029"),
+        ("AR", "005"),
+        ("AM", "145"),
+        ("AW", "029"),
+        ("AU", "053"),
+        ("AT", "155"),
+        ("AZ", "145"),
+        ("BS", "029"),
+        ("BH", "145"),
+        ("BD", "034"),
+        ("BB", "029"),
+        ("BY", "151"),
+        ("BE", "155"),
+        ("BZ", "013"),
+        ("BJ", "011"),
+        ("BM", "021"),
+        ("BT", "034"),
+        ("BO", "005"),
+        ("BA", "039"),
+        ("BW", "018"),
+        ("BV", "005"),
+        ("BR", "005"),
+        ("IO", "014"),
+        ("VG", "029"),
+        ("BN", "035"),
+        ("BG", "151"),
+        ("BF", "011"),
+        ("BI", "014"),
+        ("KH", "035"),
+        ("CM", "017"),
+        ("CA", "021"),
+        ("CV", "011"),
+        ("KY", "029"),
+        ("CF", "017"),
+        ("TD", "017"),
+        ("CL", "005"),
+        ("CN", "030"),
+        ("CX", "053"),
+        ("CC", "053"),
+        ("CO", "005"),
+        ("KM", "014"),
+        ("CK", "061"),
+        ("CR", "013"),
+        ("HR", "039"),
+        ("CU", "029"),
+        ("CY", "145"),
+        ("CZ", "151"),
+        ("CD", "017"),
+        ("DK", "154"),
+        ("DJ", "014"),
+        ("DM", "029"),
+        ("DO", "029"),
+        ("TL", "035"),
+        ("EC", "005"),
+        ("EG", "015"),
+        ("SV", "013"),
+        ("GQ", "017"),
+        ("ER", "014"),
+        ("EE", "154"),
+        ("ET", "014"),
+        ("FK", "005"),
+        ("FO", "154"),
+        ("FJ", "054"),
+        ("FI", "154"),
+        ("FR", "155"),
+        ("GF", "005"),
+        ("PF", "061"),
+        ("TF", "014"),
+        ("GA", "017"),
+        ("GM", "011"),
+        ("GE", "145"),
+        ("DE", "155"),
+        ("GH", "011"),
+        ("GI", "039"),
+        ("GR", "039"),
+        ("GL", "021"),
+        ("GD", "029"),
+        ("GP", "029"),
+        ("GU", "057"),
+        ("GT", "013"),
+        ("GN", "011"),
+        ("GW", "011"),
+        ("GY", "005"),
+        ("HT", "029"),
+        ("HM", "053"),
+        ("HN", "013"),
+        ("HK", "030"),
+        ("HU", "151"),
+        ("IS", "154"),
+        ("IN", "034"),
+        ("ID", "035"),
+        ("IR", "034"),
+        ("IQ", "145"),
+        ("IE", "154"),
+        ("IL", "145"),
+        ("IT", "039"),
+        ("CI", "011"),
+        ("JM", "029"),
+        ("JP", "030"),
+        ("JO", "145"),
+        ("KZ", "143"),
+        ("KE", "014"),
+        ("KI", "057"),
+        ("KW", "145"),
+        ("KG", "143"),
+        ("LA", "035"),
+        ("LV", "154"),
+        ("LB", "145"),
+        ("LS", "018"),
+        ("LR", "011"),
+        ("LY", "015"),
+        ("LI", "155"),
+        ("LT", "154"),
+        ("LU", "155"),
+        ("MO", "030"),
+        ("MK", "039"),
+        ("MG", "014"),
+        ("MW", "014"),
+        ("MY", "035"),
+        ("MV", "034"),
+        ("ML", "011"),
+        ("MT", "039"),
+        ("MH", "057"),
+        ("MQ", "029"),
+        ("MR", "011"),
+        ("MU", "014"),
+        ("YT", "014"),
+        ("MX", "013"),
+        ("FM", "057"),
+        ("MD", "151"),
+        ("MC", "155"),
+        ("MN", "030"),
+        ("ME", "039"),
+        ("MS", "029"),
+        ("MA", "015"),
+        ("MZ", "014"),
+        ("MM", "035"),
+        ("NA", "018"),
+        ("NR", "057"),
+        ("NP", "034"),
+        ("NL", "155"),
+        ("AN", "029"),
+        ("NC", "054"),
+        ("NZ", "053"),
+        ("NI", "013"),
+        ("NE", "011"),
+        ("NG", "011"),
+        ("NU", "061"),
+        ("NF", "053"),
+        ("KP", "030"),
+        ("MP", "057"),
+        ("NO", "154"),
+        ("OM", "145"),
+        ("PK", "034"),
+        ("PW", "057"),
+        ("PS", "145"),
+        ("PA", "013"),
+        ("PG", "054"),
+        ("PY", "005"),
+        ("PE", "005"),
+        ("PH", "035"),
+        ("PN", "061"),
+        ("PL", "151"),
+        ("PT", "039"),
+        ("PR", "029"),
+        ("QA", "145"),
+        ("CG", "017"),
+        ("RE", "014"),
+        ("RO", "151"),
+        ("RU", "151"),
+        ("RW", "014"),
+        ("SH", "011"),
+        ("KN", "029"),
+        ("LC", "029"),
+        ("PM", "021"),
+        ("VC", "029"),
+        ("WS", "061"),
+        ("SM", "039"),
+        ("ST", "017"),
+        ("SA", "145"),
+        ("SN", "011"),
+        ("RS", "039"),
+        ("CS", "039"),
+        ("SC", "014"),
+        ("SL", "011"),
+        ("SG", "035"),
+        ("SK", "151"),
+        ("SI", "039"),
+        ("SB", "054"),
+        ("SO", "014"),
+        ("ZA", "018"),
+        ("GS", "005"),
+        ("KR", "030"),
+        ("ES", "039"),
+        ("LK", "034"),
+        ("SD", "015"),
+        ("SR", "005"),
+        ("SJ", "154"),
+        ("SZ", "018"),
+        ("SE", "154"),
+        ("CH", "155"),
+        ("SY", "145"),
+        ("TW", "030"),
+        ("TJ", "143"),
+        ("TZ", "014"),
+        ("TH", "035"),
+        ("TG", "011"),
+        ("TK", "061"),
+        ("TO", "061"),
+        ("TT", "029"),
+        ("TN", "015"),
+        ("TR", "145"),
+        ("TM", "143"),
+        ("TC", "029"),
+        ("TV", "061"),
+        ("VI", "029"),
+        ("UG", "014"),
+        ("UA", "151"),
+        ("AE", "145"),
+        ("GB", "154"),
+        ("US", "021"),
+        ("UM", "057"),
+        ("UY", "005"),
+        ("UZ", "143"),
+        ("VU", "054"),
+        ("VA", "039"),
+        ("VE", "005"),
+        ("VN", "035"),
+        ("WF", "061"),
+        ("EH", "015"),
+        ("YE", "145"),
+        ("ZM", "014"),
+        ("ZW", "014"),
+        ("AX", "154"),
+        ("GG", "154"),
+        ("IM", "154"),
+        ("JE", "154"),
+    ]
+    .into_iter()
+    .collect()
+});
+
+static SUBREGION_NAMES: LazyLock<FxHashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    [
+        ("005", "South America"),
+        ("011", "Western Africa"),
+        ("013", "Central America"),
+        ("014", "Eastern Africa"),
+        ("015", "Northern Africa"),
+        ("017", "Middle Africa"),
+        ("018", "Southern Africa"),
+        ("021", "Northern America"),
+        ("029", "Caribbean"),
+        ("030", "Eastern Asia"),
+        ("034", "Southern Asia"),
+        ("035", "South-eastern Asia"),
+        ("039", "Southern Europe"),
+        ("053", "Australia and New Zealand"),
+        ("054", "Melanesia"),
+        ("057", "Micronesia"),
+        ("061", "Polynesia"),
+        ("143", "Central Asia"),
+        ("145", "Western Asia"),
+        ("151", "Eastern Europe"),
+        ("154", "Northern Europe"),
+        ("155", "Western Europe"),
+    ]
+    .into_iter()
+    .collect()
+});
+
 static EU_COUNTRIES: LazyLock<FxHashMap<&'static str, bool>> = LazyLock::new(|| {
     [
         ("AT", true),
@@ -802,6 +1284,58 @@ static EU_COUNTRIES: LazyLock<FxHashMap<&'static str, bool>> = LazyLock::new(||
     .collect()
 });
 
+static LANDLOCKED_COUNTRIES: LazyLock<FxHashMap<&'static str, bool>> = LazyLock::new(|| {
+    [
+        ("AF", true),
+        ("AD", true),
+        ("AM", true),
+        ("AT", true),
+        ("AZ", true),
+        ("BY", true),
+        ("BT", true),
+        ("BO", true),
+        ("BW", true),
+        ("BF", true),
+        ("BI", true),
+        ("CF", true),
+        ("TD", true),
+        ("CZ", true),
+        ("ET", true),
+        ("HU", true),
+        ("KZ", true),
+        ("XK", true),
+        ("KG", true),
+        ("LA", true),
+        ("LS", true),
+        ("LI", true),
+        ("LU", true),
+        ("MW", true),
+        ("ML", true),
+        ("MD", true),
+        ("MN", true),
+        ("NP", true),
+        ("NE", true),
+        ("MK", true),
+        ("PY", true),
+        ("RW", true),
+        ("SM", true),
+        ("RS", true),
+        ("SK", true),
+        ("SS", true),
+        ("SZ", true),
+        ("CH", true),
+        ("TJ", true),
+        ("TM", true),
+        ("UG", true),
+        ("UZ", true),
+        ("VA", true),
+        ("ZM", true),
+        ("ZW", true),
+    ]
+    .into_iter()
+    .collect()
+});
+
 fn format_offset(offset_secs: i32) -> String {
     let hours = offset_secs / 3600;
     let mins = (offset_secs.abs() % 3600) / 60;
@@ -828,6 +1362,38 @@ fn calculate_dst(tz: &Tz, offset_secs: i32) -> bool {
     offset_secs != jan.min(jul)
 }
 
+/// Returns the first instant after `from` at which the UTC offset of `tz` changes.
+///
+/// Scans ahead in one-day steps for up to a year and then bisects the day containing
+/// the change down to the second. Returns `None` if the offset stays the same for the
+/// whole year, i.e. the zone does not observe DST.
+pub(crate) fn next_offset_change(tz: &Tz, from: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let offset_at = |t: DateTime<Utc>| t.with_timezone(tz).offset().fix().local_minus_utc();
+    let current = offset_at(from);
+
+    let mut day_start = from;
+    let (mut low, mut high) = loop {
+        let day_end = day_start + TimeDelta::days(1);
+        if offset_at(day_end) != current {
+            break (day_start, day_end);
+        }
+        if day_end - from > TimeDelta::days(366) {
+            return None;
+        }
+        day_start = day_end;
+    };
+
+    while high - low > TimeDelta::seconds(1) {
+        let mid = DateTime::from_timestamp(low.timestamp() + (high - low).num_seconds() / 2, 0)?;
+        if offset_at(mid) == current {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    DateTime::from_timestamp(high.timestamp(), 0)
+}
+
 /// Input structure for the [`enrich_place`] function.
 ///
 /// This struct contains the basic geographic data that will be enriched with additional
@@ -837,22 +1403,32 @@ fn calculate_dst(tz: &Tz, offset_secs: i32) -> bool {
 pub struct PlaceInput<'a> {
     /// City name
     pub city: &'a str,
+    /// ASCII-only city name
+    pub city_ascii: &'a str,
     /// Region/state name
     pub region: &'a str,
     /// Region code
     pub region_code: &'a str,
     /// District/county name
     pub district: &'a str,
+    /// Commune/municipality name
+    pub subdistrict: &'a str,
     /// ISO country code
     pub country_code: &'a str,
     /// Postal/ZIP code
     pub postal_code: &'a str,
     /// IANA timezone identifier
     pub timezone: &'a str,
+    /// GeoNames feature code
+    pub feature_code: &'a str,
+    /// Date GeoNames last modified the entry
+    pub modified: Option<NaiveDate>,
     /// Latitude coordinate
     pub latitude: f64,
     /// Longitude coordinate
     pub longitude: f64,
+    /// Localized names of the city
+    pub alternate_names: AlternateNames<'a>,
 }
 
 /// Enriches basic place data with computed fields.
@@ -865,9 +1441,11 @@ pub struct PlaceInput<'a> {
 ///
 /// 1. **Timezone Parsing:** Parses the IANA timezone to extract current offset, abbreviation, and DST status using `chrono-tz`
 /// 2. **Country Lookup:** Maps country code to full country name using static hash map
-/// 3. **Currency Lookup:** Maps country code to ISO 4217 currency code
+/// 3. **Currency Lookup:** Maps country code to ISO 4217 currency code, then the code
+///    to its symbol and number of decimal places
 /// 4. **Continent Lookup:** Maps country code to continent code and name
-/// 5. **EU Status:** Checks if country is an EU member state
+/// 5. **Subregion Lookup:** Maps country code to UN M49 subregion code and name
+/// 6. **EU Status:** Checks if country is an EU member state
 ///
 /// # Static Data Sources
 ///
@@ -875,9 +1453,14 @@ pub struct PlaceInput<'a> {
 ///
 /// - `COUNTRY_NAMES` - 200+ country code to name mappings
 /// - `COUNTRY_CURRENCIES` - 200+ country code to currency mappings
+/// - `COUNTRY_MULTIPLE_CURRENCIES` - Countries with more than one official currency
+/// - `CURRENCY_INFO` - 150+ currency code to symbol and minor unit mappings
 /// - `COUNTRY_CONTINENTS` - 200+ country code to continent mappings
 /// - `CONTINENT_NAMES` - 7 continent code to name mappings
+/// - `COUNTRY_SUBREGIONS` - 200+ country code to M49 subregion mappings
+/// - `SUBREGION_NAMES` - 22 M49 subregion code to name mappings
 /// - `EU_COUNTRIES` - 27 EU member states
+/// - `LANDLOCKED_COUNTRIES` - 45 countries without a sea coast
 ///
 /// # DST Detection
 ///
@@ -892,69 +1475,202 @@ pub struct PlaceInput<'a> {
 ///
 /// let input = PlaceInput {
 ///     city: "New York",
+///     city_ascii: "New York",
 ///     region: "New York",
 ///     region_code: "NY",
 ///     district: "New York County",
+///     subdistrict: "",
 ///     country_code: "US",
 ///     postal_code: "10001",
 ///     timezone: "America/New_York",
+///     feature_code: "PPL",
+///     modified: None,
 ///     latitude: 40.7128,
 ///     longitude: -74.0060,
+///     alternate_names: Default::default(),
 /// };
 ///
 /// let place = enrich_place(input);
 /// assert_eq!(place.country_name, "United States");
 /// assert_eq!(place.currency, "USD");
+/// assert_eq!(place.currency_symbol, "$");
+/// assert_eq!(place.currency_decimals, 2);
+/// assert_eq!(place.currencies, ["USD"]);
 /// assert_eq!(place.continent_name, "North America");
+/// assert_eq!(place.subregion_name, "Northern America");
 /// assert_eq!(place.is_eu, false);
+/// assert_eq!(place.is_landlocked, false);
+/// # }
+/// ```
+///
+/// Every country the database builder downloads has a name, a currency and a continent:
+///
+/// ```
+/// # include!("../build/countries.rs");
+/// use genom::enrichment::{enrich_place_ref, PlaceInput};
+///
+/// # fn main() {
+/// for &country_code in COUNTRIES {
+///     let input = PlaceInput {
+///         city: "",
+///         city_ascii: "",
+///         region: "",
+///         region_code: "",
+///         district: "",
+///         subdistrict: "",
+///         country_code,
+///         postal_code: "",
+///         timezone: "UTC",
+///         feature_code: "PPL",
+///         modified: None,
+///         latitude: 0.0,
+///         longitude: 0.0,
+///         alternate_names: Default::default(),
+///     };
+///     let place = enrich_place_ref(input);
+///     assert!(!place.country_name.is_empty(), "no name for {}", country_code);
+///     assert!(!place.currency.is_empty(), "no currency for {}", country_code);
+///     assert!(!place.continent_code.is_empty(), "no continent for {}", country_code);
+///     assert!(!place.continent_name.is_empty(), "no continent name for {}", country_code);
+/// }
 /// # }
 /// ```
 pub fn enrich_place(input: PlaceInput) -> Place {
-    let (timezone_abbr, utc_offset, utc_offset_str, dst_active) = Tz::from_str(input.timezone)
-        .ok()
-        .map(|tz| {
-            let local = Utc::now().with_timezone(&tz);
-            let offset_secs = local.offset().fix().local_minus_utc();
-            (
-                format!("{}", local.format("%Z")),
-                offset_secs,
-                format_offset(offset_secs),
-                calculate_dst(&tz, offset_secs),
-            )
-        })
-        .unwrap_or_else(|| (String::new(), 0, "UTC+0".to_string(), false));
+    let (timezone_abbr, utc_offset, utc_offset_std, utc_offset_str, dst_active) =
+        Tz::from_str(input.timezone)
+            .ok()
+            .map(|tz| {
+                let local = Utc::now().with_timezone(&tz);
+                let offset_secs = local.offset().fix().local_minus_utc();
+                (
+                    format!("{}", local.format("%Z")),
+                    offset_secs,
+                    local.offset().base_utc_offset().num_seconds() as i32,
+                    format_offset(offset_secs),
+                    calculate_dst(&tz, offset_secs),
+                )
+            })
+            .unwrap_or_else(|| (String::new(), 0, 0, "UTC+0".to_string(), false));
 
+    let place = enrich_place_ref(input);
     Place {
-        city: input.city.to_string(),
-        region: input.region.to_string(),
-        region_code: input.region_code.to_string(),
-        district: input.district.to_string(),
-        country_code: input.country_code.to_string(),
-        country_name: COUNTRY_NAMES
-            .get(input.country_code)
-            .unwrap_or(&"Unknown")
-            .to_string(),
-        postal_code: input.postal_code.to_string(),
-        timezone: input.timezone.to_string(),
+        city: place.city.to_string(),
+        city_ascii: place.city_ascii.to_string(),
+        region: place.region.to_string(),
+        region_code: place.region_code.to_string(),
+        district: place.district.to_string(),
+        subdistrict: place.subdistrict.to_string(),
+        feature_code: place.feature_code.to_string(),
+        modified: place.modified,
+        country_code: place.country_code.to_string(),
+        country_name: place.country_name.to_string(),
+        postal_code: place.postal_code.to_string(),
+        timezone: place.timezone.to_string(),
         timezone_abbr,
         utc_offset,
+        utc_offset_std,
         utc_offset_str,
+        latitude: place.latitude,
+        longitude: place.longitude,
+        currency: place.currency.to_string(),
+        currency_symbol: place.currency_symbol.to_string(),
+        currency_decimals: place.currency_decimals,
+        currencies: place.currencies.iter().map(|c| c.to_string()).collect(),
+        continent_code: place.continent_code.to_string(),
+        continent_name: place.continent_name.to_string(),
+        subregion_code: place.subregion_code.to_string(),
+        subregion_name: place.subregion_name.to_string(),
+        is_eu: place.is_eu,
+        is_landlocked: place.is_landlocked,
+        dst_active,
+        alternate_names: place
+            .alternate_names
+            .iter()
+            .map(|(lang, name)| (lang.to_string(), name.to_string()))
+            .collect(),
+    }
+}
+
+/// Enriches basic place data without allocating.
+///
+/// This is the zero-copy counterpart of [`enrich_place`]: string fields borrow from the
+/// input or from the static lookup tables. The time-dependent timezone fields are not
+/// computed; convert the result with [`PlaceRef::to_owned`] when they are needed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// use genom::enrichment::{enrich_place_ref, PlaceInput};
+///
+/// let input = PlaceInput {
+///     city: "Tokyo",
+///     city_ascii: "Tokyo",
+///     region: "Tokyo",
+///     region_code: "13",
+///     district: "",
+///     subdistrict: "",
+///     country_code: "JP",
+///     postal_code: "100-0001",
+///     timezone: "Asia/Tokyo",
+///     feature_code: "PPLC",
+///     modified: None,
+///     latitude: 35.6762,
+///     longitude: 139.6503,
+///     alternate_names: Default::default(),
+/// };
+///
+/// let place = enrich_place_ref(input);
+/// assert_eq!(place.country_name, "Japan");
+/// assert_eq!(place.currency, "JPY");
+/// assert_eq!(place.currency_decimals, 0);
+/// assert_eq!(place.continent_name, "Asia");
+/// # }
+/// ```
+pub fn enrich_place_ref(input: PlaceInput<'_>) -> PlaceRef<'_> {
+    let continent_code = COUNTRY_CONTINENTS.get(input.country_code).copied();
+    let subregion_code = COUNTRY_SUBREGIONS.get(input.country_code).copied();
+    let primary_currency = COUNTRY_CURRENCIES.get(input.country_code);
+    let currency = primary_currency.copied().unwrap_or("");
+    let currencies = COUNTRY_MULTIPLE_CURRENCIES
+        .get(input.country_code)
+        .copied()
+        .unwrap_or_else(|| primary_currency.map(std::slice::from_ref).unwrap_or(&[]));
+    let (currency_symbol, currency_decimals) =
+        CURRENCY_INFO.get(currency).copied().unwrap_or(("", 2));
+
+    PlaceRef {
+        city: input.city,
+        city_ascii: input.city_ascii,
+        region: input.region,
+        region_code: input.region_code,
+        district: input.district,
+        subdistrict: input.subdistrict,
+        feature_code: input.feature_code,
+        modified: input.modified,
+        country_code: input.country_code,
+        country_name: COUNTRY_NAMES
+            .get(input.country_code)
+            .copied()
+            .unwrap_or("Unknown"),
+        postal_code: input.postal_code,
+        timezone: input.timezone,
         latitude: input.latitude,
         longitude: input.longitude,
-        currency: COUNTRY_CURRENCIES
-            .get(input.country_code)
-            .unwrap_or(&"")
-            .to_string(),
-        continent_code: COUNTRY_CONTINENTS
-            .get(input.country_code)
-            .unwrap_or(&"")
-            .to_string(),
-        continent_name: COUNTRY_CONTINENTS
-            .get(input.country_code)
-            .and_then(|c| CONTINENT_NAMES.get(c))
-            .unwrap_or(&"Unknown")
-            .to_string(),
+        currency,
+        currency_symbol,
+        currency_decimals,
+        currencies,
+        continent_code: continent_code.unwrap_or(""),
+        continent_name: continent_code
+            .and_then(|c| CONTINENT_NAMES.get(c).copied())
+            .unwrap_or("Unknown"),
+        subregion_code: subregion_code.unwrap_or(""),
+        subregion_name: subregion_code
+            .and_then(|c| SUBREGION_NAMES.get(c).copied())
+            .unwrap_or("Unknown"),
         is_eu: EU_COUNTRIES.contains_key(input.country_code),
-        dst_active,
+        is_landlocked: LANDLOCKED_COUNTRIES.contains_key(input.country_code),
+        alternate_names: input.alternate_names,
     }
 }
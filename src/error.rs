@@ -0,0 +1,148 @@
+//! Error types returned by the library.
+//!
+//! - [`DatabaseError`] - Returned by the non-panicking entry points
+//!   ([`Geocoder::try_global()`](crate::Geocoder::try_global), [`warm_up()`](crate::warm_up),
+//!   [`try_lookup()`](crate::try_lookup)) when the embedded database cannot be decoded
+//! - [`ParseLocationError`] - Returned when parsing a [`Location`](crate::Location) from a string
+
+#![warn(missing_docs)]
+
+use std::fmt;
+
+/// An error encountered while decoding the binary place database.
+///
+/// Database errors are not recoverable at runtime - the embedded data is fixed at
+/// compile time - so the intended use is to surface them early (e.g., during
+/// application startup via [`warm_up()`](crate::warm_up)) rather than on the first
+/// user request.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// if let Err(e) = genom::warm_up() {
+///     eprintln!("geocoder unavailable: {}", e);
+///     std::process::exit(1);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DatabaseError {
+    /// The data ended before all declared sections could be read.
+    Truncated,
+    /// An entry in the string table is not valid UTF-8.
+    InvalidUtf8,
+    /// The data does not start with the database magic bytes.
+    InvalidMagic,
+    /// The database was written in a format version this build cannot read.
+    UnsupportedVersion(u32),
+    /// A database file could not be read.
+    Io(std::io::ErrorKind),
+    /// The shard directory has no shard for the requested country.
+    MissingShard(String),
+    /// The spatial grid lists the same cell twice; holds the cell's grid key.
+    DuplicateGridKey((i16, i16)),
+    /// The spatial grid's cell keys are not in ascending order.
+    UnsortedGrid,
+    /// A column refers to a string or place that does not exist, or an offset column
+    /// decreases.
+    IndexOutOfRange,
+    /// The data is compressed with a codec whose feature is not enabled; holds the
+    /// feature name.
+    UnsupportedCompression(&'static str),
+    /// The global geocoder has no places to load: the crate was built with
+    /// `no-build-database`, or the embedded database contains no places.
+    EmptyDatabase,
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "database is truncated"),
+            Self::InvalidUtf8 => write!(f, "database string table contains invalid UTF-8"),
+            Self::InvalidMagic => write!(f, "data is not a genom database"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported database format version {}", version)
+            }
+            Self::Io(kind) => write!(f, "failed to read database: {}", kind),
+            Self::MissingShard(country) => write!(f, "no database shard for country {}", country),
+            Self::DuplicateGridKey((lat, lon)) => {
+                write!(f, "grid cell ({}, {}) appears more than once", lat, lon)
+            }
+            Self::UnsortedGrid => write!(f, "grid cells are not sorted by key"),
+            Self::IndexOutOfRange => write!(f, "database refers to an entry outside its tables"),
+            Self::UnsupportedCompression(codec) => write!(
+                f,
+                "database is {} compressed; enable the `{}` feature to load it",
+                codec, codec
+            ),
+            Self::EmptyDatabase => write!(
+                f,
+                "the embedded database is missing (built with `no-build-database`) or has \
+                 no places; load one at runtime with Geocoder::from_path or Geocoder::from_bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DatabaseError {}
+
+impl From<std::io::Error> for DatabaseError {
+    fn from(e: std::io::Error) -> Self {
+        match e.kind() {
+            std::io::ErrorKind::UnexpectedEof => Self::Truncated,
+            kind => Self::Io(kind),
+        }
+    }
+}
+
+impl From<std::string::FromUtf8Error> for DatabaseError {
+    fn from(_: std::string::FromUtf8Error) -> Self {
+        Self::InvalidUtf8
+    }
+}
+
+/// An error returned when parsing a [`Location`](crate::Location) from a string fails.
+///
+/// # Examples
+///
+/// ```
+/// use genom::{Location, ParseLocationError};
+///
+/// assert_eq!(
+///     "40.7128".parse::<Location>().unwrap_err(),
+///     ParseLocationError::WrongComponentCount(1)
+/// );
+/// assert_eq!(
+///     "91.0,0.0".parse::<Location>().unwrap_err(),
+///     ParseLocationError::LatitudeOutOfRange(91.0)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseLocationError {
+    /// The input did not contain exactly two components; holds the number found.
+    WrongComponentCount(usize),
+    /// A component is not a valid floating point number; holds the offending text.
+    InvalidNumber(String),
+    /// The latitude is outside -90 to 90 (or not finite).
+    LatitudeOutOfRange(f64),
+    /// The longitude is outside -180 to 180 (or not finite).
+    LongitudeOutOfRange(f64),
+}
+
+impl fmt::Display for ParseLocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongComponentCount(n) => {
+                write!(f, "expected \"lat,lon\" with 2 components, found {}", n)
+            }
+            Self::InvalidNumber(s) => write!(f, "invalid coordinate {:?}", s),
+            Self::LatitudeOutOfRange(v) => write!(f, "latitude {} is outside -90 to 90", v),
+            Self::LongitudeOutOfRange(v) => write!(f, "longitude {} is outside -180 to 180", v),
+        }
+    }
+}
+
+impl std::error::Error for ParseLocationError {}
@@ -0,0 +1,320 @@
+//! A tiny synthetic database for tests and benchmarks.
+//!
+//! Enabled with the `test-fixtures` feature. [`database()`] builds a [`Database`] in
+//! memory from the handful of places in [`PLACES`], so code that depends on a
+//! [`Geocoder`] can be tested offline with deterministic results, independent of the
+//! downloaded GeoNames data and the build pipeline.
+//!
+//! The places cover a dense area (Paris and Boulogne-Billancourt share a grid
+//! neighborhood), a national border (Geneva and Annemasse), a place near the
+//! antimeridian (Suva) and places without optional fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use genom::fixtures;
+//!
+//! let geocoder = fixtures::geocoder();
+//! let place = geocoder.lookup(48.8566, 2.3522).unwrap();
+//! assert_eq!(place.city, "Paris");
+//! assert_eq!(place.country_name, "France");
+//!
+//! // Ocean coordinates have no place nearby
+//! assert!(geocoder.lookup(0.0, -160.0).is_none());
+//! ```
+//!
+//! Near the Swiss-French border the nearest place decides the country, and enrichment
+//! follows it:
+//!
+//! ```
+//! let geocoder = genom::fixtures::geocoder();
+//!
+//! let geneva = geocoder.lookup(46.2000, 6.1700).unwrap();
+//! assert_eq!(geneva.city, "Genève");
+//! assert_eq!(geneva.country_code, "CH");
+//! assert_eq!(geneva.country_name, "Switzerland");
+//! assert_eq!(geneva.region, "Geneva");
+//! assert_eq!(geneva.timezone, "Europe/Zurich");
+//! assert_eq!(geneva.utc_offset_std, 3600);
+//! assert_eq!(geneva.currency, "CHF");
+//! assert_eq!(geneva.continent_code, "EU");
+//! assert!(!geneva.is_eu);
+//! assert!(geneva.is_landlocked);
+//! assert_eq!(geneva.name_in("de").as_deref(), Some("Genf"));
+//!
+//! let annemasse = geocoder.lookup(46.1990, 6.2000).unwrap();
+//! assert_eq!(annemasse.city, "Annemasse");
+//! assert_eq!(annemasse.country_code, "FR");
+//! assert_eq!(annemasse.currency, "EUR");
+//! assert!(annemasse.is_eu);
+//! ```
+//!
+//! The grid search does not wrap around the antimeridian, the exact search does:
+//!
+//! ```
+//! let geocoder = genom::fixtures::geocoder();
+//!
+//! // East of 180°, about 175 km from Suva
+//! assert!(geocoder.lookup(-18.14, -179.9).is_none());
+//! let suva = geocoder.lookup_exact(-18.14, -179.9).unwrap();
+//! assert_eq!(suva.city, "Suva");
+//! assert_eq!(suva.country_name, "Fiji");
+//! assert_eq!(suva.continent_code, "OC");
+//! assert_eq!(suva.timezone, "Pacific/Fiji");
+//! assert_eq!(suva.postal_code, "");
+//! ```
+
+#![warn(missing_docs)]
+
+use crate::types::{CompactPlace, Database, Grid};
+use crate::Geocoder;
+use chrono::NaiveDate;
+use rustc_hash::FxHashMap;
+
+/// A place in the fixture database.
+#[derive(Debug, Clone, Copy)]
+pub struct FixturePlace {
+    /// City or locality name
+    pub city: &'static str,
+    /// ASCII-only city name
+    pub city_ascii: &'static str,
+    /// State/province name
+    pub region: &'static str,
+    /// ISO 3166-2 region code
+    pub region_code: &'static str,
+    /// County/district name
+    pub district: &'static str,
+    /// Commune/municipality name
+    pub subdistrict: &'static str,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: &'static str,
+    /// Postal/ZIP code
+    pub postal_code: &'static str,
+    /// IANA timezone identifier
+    pub timezone: &'static str,
+    /// GeoNames feature code (e.g., "PPLC" for a national capital)
+    pub feature_code: &'static str,
+    /// Date GeoNames last modified the entry (`YYYY-MM-DD`)
+    pub modified: &'static str,
+    /// Number of inhabitants (0 if unknown)
+    pub population: u32,
+    /// Latitude in decimal degrees
+    pub latitude: f64,
+    /// Longitude in decimal degrees
+    pub longitude: f64,
+    /// Localized names as `(language, name)` pairs
+    pub alternate_names: &'static [(&'static str, &'static str)],
+}
+
+/// The places contained in the fixture database, in index order.
+pub const PLACES: &[FixturePlace] = &[
+    FixturePlace {
+        city: "Paris",
+        city_ascii: "Paris",
+        region: "Île-de-France",
+        region_code: "IDF",
+        district: "Paris",
+        subdistrict: "Paris 1er Arrondissement",
+        country_code: "FR",
+        postal_code: "75001",
+        timezone: "Europe/Paris",
+        feature_code: "PPLC",
+        modified: "2024-09-05",
+        population: 2_138_551,
+        latitude: 48.85341,
+        longitude: 2.3488,
+        alternate_names: &[("ja", "パリ")],
+    },
+    FixturePlace {
+        city: "Boulogne-Billancourt",
+        city_ascii: "Boulogne-Billancourt",
+        region: "Île-de-France",
+        region_code: "IDF",
+        district: "Hauts-de-Seine",
+        subdistrict: "",
+        country_code: "FR",
+        postal_code: "92100",
+        timezone: "Europe/Paris",
+        feature_code: "PPLA3",
+        modified: "2023-11-21",
+        population: 121_334,
+        latitude: 48.83545,
+        longitude: 2.24128,
+        alternate_names: &[],
+    },
+    FixturePlace {
+        city: "Berlin",
+        city_ascii: "Berlin",
+        region: "Berlin",
+        region_code: "BE",
+        district: "",
+        subdistrict: "",
+        country_code: "DE",
+        postal_code: "10115",
+        timezone: "Europe/Berlin",
+        feature_code: "PPLC",
+        modified: "2024-06-14",
+        population: 3_426_354,
+        latitude: 52.52437,
+        longitude: 13.41053,
+        alternate_names: &[("ru", "Берлин")],
+    },
+    FixturePlace {
+        city: "München",
+        city_ascii: "Muenchen",
+        region: "Bavaria",
+        region_code: "BY",
+        district: "Upper Bavaria",
+        subdistrict: "",
+        country_code: "DE",
+        postal_code: "80331",
+        timezone: "Europe/Berlin",
+        feature_code: "PPLA",
+        modified: "2024-03-09",
+        population: 1_260_391,
+        latitude: 48.13743,
+        longitude: 11.57549,
+        alternate_names: &[("en", "Munich"), ("it", "Monaco di Baviera")],
+    },
+    FixturePlace {
+        city: "New York City",
+        city_ascii: "New York City",
+        region: "New York",
+        region_code: "NY",
+        district: "New York County",
+        subdistrict: "",
+        country_code: "US",
+        postal_code: "10001",
+        timezone: "America/New_York",
+        feature_code: "PPL",
+        modified: "2024-10-02",
+        population: 8_804_190,
+        latitude: 40.71427,
+        longitude: -74.00597,
+        alternate_names: &[],
+    },
+    FixturePlace {
+        city: "Tokyo",
+        city_ascii: "Tokyo",
+        region: "Tokyo",
+        region_code: "13",
+        district: "",
+        subdistrict: "",
+        country_code: "JP",
+        postal_code: "100-0001",
+        timezone: "Asia/Tokyo",
+        feature_code: "PPLC",
+        modified: "2024-05-27",
+        population: 8_336_599,
+        latitude: 35.6895,
+        longitude: 139.69171,
+        alternate_names: &[("ja", "東京")],
+    },
+    FixturePlace {
+        city: "Suva",
+        city_ascii: "Suva",
+        region: "Central",
+        region_code: "C",
+        district: "",
+        subdistrict: "",
+        country_code: "FJ",
+        postal_code: "",
+        timezone: "Pacific/Fiji",
+        feature_code: "PPLC",
+        modified: "2023-08-30",
+        population: 77_366,
+        latitude: -18.14161,
+        longitude: 178.44149,
+        alternate_names: &[],
+    },
+    FixturePlace {
+        city: "Genève",
+        city_ascii: "Geneve",
+        region: "Geneva",
+        region_code: "GE",
+        district: "",
+        subdistrict: "",
+        country_code: "CH",
+        postal_code: "1201",
+        timezone: "Europe/Zurich",
+        feature_code: "PPLA",
+        modified: "2024-04-11",
+        population: 183_981,
+        latitude: 46.20222,
+        longitude: 6.14569,
+        alternate_names: &[("de", "Genf"), ("en", "Geneva")],
+    },
+    FixturePlace {
+        city: "Annemasse",
+        city_ascii: "Annemasse",
+        region: "Auvergne-Rhône-Alpes",
+        region_code: "ARA",
+        district: "Haute-Savoie",
+        subdistrict: "",
+        country_code: "FR",
+        postal_code: "74100",
+        timezone: "Europe/Paris",
+        feature_code: "PPLA3",
+        modified: "2023-10-17",
+        population: 28_275,
+        latitude: 46.19439,
+        longitude: 6.23775,
+        alternate_names: &[],
+    },
+];
+
+/// Builds the fixture database from [`PLACES`].
+///
+/// Strings are interned and the grid is built the same way the database builder does,
+/// so the result behaves like a (very small) real database.
+pub fn database() -> Database {
+    let mut db = Database::default();
+    let mut string_map: FxHashMap<&str, u32> = FxHashMap::default();
+    let mut intern = |db: &mut Database, s: &'static str| {
+        *string_map.entry(s).or_insert_with(|| {
+            db.strings.push(s.to_string());
+            db.strings.len() as u32 - 1
+        })
+    };
+    intern(&mut db, "");
+
+    let mut cells: FxHashMap<(i16, i16), Vec<u32>> = FxHashMap::default();
+    for place in PLACES {
+        let compact = CompactPlace {
+            city: intern(&mut db, place.city),
+            city_ascii: intern(&mut db, place.city_ascii),
+            region: intern(&mut db, place.region),
+            region_code: intern(&mut db, place.region_code),
+            district: intern(&mut db, place.district),
+            subdistrict: intern(&mut db, place.subdistrict),
+            country_code: intern(&mut db, place.country_code),
+            postal_code: intern(&mut db, place.postal_code),
+            timezone: intern(&mut db, place.timezone),
+            feature_code: intern(&mut db, place.feature_code),
+            modified: NaiveDate::parse_from_str(place.modified, "%Y-%m-%d")
+                .ok()
+                .zip(NaiveDate::from_ymd_opt(1970, 1, 1))
+                .and_then(|(date, epoch)| u16::try_from((date - epoch).num_days()).ok())
+                .unwrap_or(0),
+            population: place.population,
+            lat: (place.latitude * 100000.0).round() as i32,
+            lon: (place.longitude * 100000.0).round() as i32,
+        };
+        for &(lang, name) in place.alternate_names {
+            let entry = (intern(&mut db, lang), intern(&mut db, name));
+            db.names.push(entry);
+        }
+        db.push(compact);
+        cells
+            .entry(((compact.lat / 10000) as i16, (compact.lon / 10000) as i16))
+            .or_default()
+            .push(db.len() as u32 - 1);
+    }
+    db.grid = Grid::from_cells(cells);
+    db
+}
+
+/// Returns a [`Geocoder`] over the fixture [`database()`].
+pub fn geocoder() -> Geocoder {
+    Geocoder::from_database(database())
+}
@@ -0,0 +1,95 @@
+//! Exact nearest-neighbor index over place coordinates.
+//!
+//! Places are stored as points on the unit sphere, so the straight-line (chord)
+//! distance between two points orders them exactly like the great-circle distance,
+//! including across the antimeridian and near the poles. The tree is implicit: the
+//! points are arranged so that every subrange has its splitting point at its middle,
+//! which needs no node pointers beyond the points themselves.
+
+use crate::types::{Database, Location};
+
+/// A point on the unit sphere and the index of the place it belongs to.
+#[derive(Clone, Copy)]
+struct Point {
+    xyz: [f64; 3],
+    idx: u32,
+}
+
+/// Implicit 3-d tree over every place in a [`Database`].
+pub(crate) struct KdTree {
+    points: Vec<Point>,
+}
+
+/// Converts degrees to a unit vector.
+fn unit_vector(latitude: f64, longitude: f64) -> [f64; 3] {
+    let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn squared_distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]) * (a[i] - b[i])).sum()
+}
+
+impl KdTree {
+    /// Builds the tree in O(n log n) by recursive median selection.
+    pub(crate) fn build(db: &Database) -> Self {
+        let mut points: Vec<Point> = (0..db.len())
+            .map(|idx| {
+                let location = db.location(idx);
+                Point {
+                    xyz: unit_vector(location.latitude, location.longitude),
+                    idx: idx as u32,
+                }
+            })
+            .collect();
+        Self::arrange(&mut points, 0);
+        Self { points }
+    }
+
+    fn arrange(points: &mut [Point], axis: usize) {
+        if points.len() <= 1 {
+            return;
+        }
+        let mid = points.len() / 2;
+        points.select_nth_unstable_by(mid, |a, b| a.xyz[axis].total_cmp(&b.xyz[axis]));
+        let (left, right) = points.split_at_mut(mid);
+        Self::arrange(left, (axis + 1) % 3);
+        Self::arrange(&mut right[1..], (axis + 1) % 3);
+    }
+
+    /// Returns the index of the place closest to `location`, or `None` if the tree is empty.
+    ///
+    /// Of several places at the same distance, the one with the lowest index is returned.
+    pub(crate) fn nearest(&self, location: &Location) -> Option<usize> {
+        let target = unit_vector(location.latitude, location.longitude);
+        let mut best = (f64::INFINITY, None);
+        Self::search(&self.points, 0, &target, &mut best);
+        best.1
+    }
+
+    fn search(points: &[Point], axis: usize, target: &[f64; 3], best: &mut (f64, Option<usize>)) {
+        if points.is_empty() {
+            return;
+        }
+        let mid = points.len() / 2;
+        let point = &points[mid];
+        let distance = squared_distance(&point.xyz, target);
+        let idx = point.idx as usize;
+        if distance < best.0 || (distance == best.0 && best.1.is_some_and(|best| idx < best)) {
+            *best = (distance, Some(idx));
+        }
+
+        let offset = target[axis] - point.xyz[axis];
+        let (near, far) = if offset < 0.0 {
+            (&points[..mid], &points[mid + 1..])
+        } else {
+            (&points[mid + 1..], &points[..mid])
+        };
+        let next = (axis + 1) % 3;
+        Self::search(near, next, target, best);
+        // Equal distances are still searched so that ties resolve to the lowest index
+        if offset * offset <= best.0 {
+            Self::search(far, next, target, best);
+        }
+    }
+}
@@ -6,8 +6,8 @@
 //!
 //! # Features
 //!
-//! - **Simple API** - Single function call: [`lookup(lat, lon)`](lookup)
-//! - **Rich Data** - Returns 18 fields including timezone, currency, postal code, region, EU status
+//! - **Simple API** - Single function call: [`lookup(lat, lon)`](lookup) or [`lookup_loc(location)`](lookup_loc)
+//! - **Rich Data** - Returns 30 fields including timezone, currency, postal code, region, EU status
 //! - **Fast Lookups** - Grid-based spatial indexing for sub-millisecond queries
 //! - **Zero Config** - Database builds automatically on first install from GeoNames data
 //! - **Thread-Safe** - Global singleton with lazy initialization, safe for concurrent access
@@ -79,10 +79,11 @@
 //!
 //! 1. Quantize input coordinates to grid key (0.1° resolution)
 //! 2. Search target cell and 8 neighboring cells (3×3 grid)
-//! 3. Calculate haversine distance to all candidates
+//! 3. Calculate equirectangular distance to all candidates
 //! 4. Return nearest place with enriched metadata
 //!
-//! This provides O(1) average-case lookup with typically 10-50 candidates to check.
+//! Each cell is located by binary search over the sorted cell keys, and typically only 10-50
+//! candidates need checking.
 //!
 //! ## Data Enrichment
 //!
@@ -102,7 +103,8 @@
 //! - **Binary size increase**: ~20-30 MB (embedded database)
 //!
 //! The database is initialized lazily on first use and cached in a static `OnceLock`,
-//! making it safe and efficient for concurrent access.
+//! making it safe and efficient for concurrent access. Call [`warm_up()`] during startup
+//! to pay the initialization cost before the first request instead.
 //!
 //! # Build Process
 //!
@@ -127,6 +129,13 @@
 //! genom = { version = "0.1", features = ["no-build-database"] }
 //! ```
 //!
+//! Without an embedded database, [`Geocoder::try_global()`] and [`try_lookup()`] fail
+//! with [`DatabaseError::EmptyDatabase`] (and [`lookup()`] panics) rather than report
+//! every coordinate as unknown. Load a database at runtime with
+//! [`Geocoder::from_path()`], [`Geocoder::from_bytes()`] or [`Geocoder::from_reader()`].
+//! For tests, the `test-fixtures` feature provides a tiny in-memory database in the
+//! `fixtures` module.
+//!
 //! # Thread Safety
 //!
 //! All operations are thread-safe:
@@ -194,12 +203,34 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "cache")]
+mod cache;
 mod database;
+mod dms;
 pub mod enrichment;
+mod error;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
+mod kdtree;
+#[cfg(feature = "simd")]
+mod simd;
+mod solar;
 pub mod types;
+mod utm;
+#[cfg(feature = "water")]
+mod water;
 
-pub use database::Geocoder;
-pub use types::{Location, Place};
+#[cfg(feature = "cache")]
+pub use cache::{CacheStats, CachedGeocoder};
+pub use database::{normalize_name, Geocoder};
+pub use error::{DatabaseError, ParseLocationError};
+pub use types::{
+    AdminKind, AdminLevel, AlternateNames, DatabaseDiff, DatabaseInfo, DatabaseStats,
+    DistanceMetric, Ellipsoidal, Equirectangular, Haversine, Hemisphere, Location, LookupDebug,
+    LookupOptions, MovedPlace, Place, PlaceField, PlaceRef, Utm,
+};
+#[cfg(feature = "water")]
+pub use types::{LookupResult, WaterBody};
 
 /// Performs reverse geocoding on the given coordinates, returning enriched place data if found.
 ///
@@ -239,5 +270,223 @@ pub use types::{Location, Place};
 /// # }
 /// ```
 pub fn lookup(latitude: f64, longitude: f64) -> Option<Place> {
-    Geocoder::global().lookup(latitude, longitude)
+    Geocoder::with_current(|geocoder| geocoder.lookup(latitude, longitude))
+}
+
+/// Performs reverse geocoding on a typed coordinate.
+///
+/// Accepts anything convertible into a [`Location`]: a `Location` itself, a
+/// `(latitude, longitude)` tuple, or a `[latitude, longitude]` array. Passing a typed
+/// coordinate avoids accidentally transposing latitude and longitude.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// use genom::Location;
+///
+/// let paris = Location::new(48.8566, 2.3522);
+/// let a = genom::lookup_loc(paris);
+/// let b = genom::lookup_loc((48.8566, 2.3522));
+/// let c = genom::lookup_loc([48.8566, 2.3522]);
+/// assert_eq!(a, b);
+/// assert_eq!(b, c);
+/// # }
+/// ```
+pub fn lookup_loc(location: impl Into<Location>) -> Option<Place> {
+    let location = location.into();
+    lookup(location.latitude, location.longitude)
+}
+
+/// Performs reverse geocoding restricted to a known country.
+///
+/// Only places whose country code matches `country_code` are considered, expanding the
+/// search radius when no match is nearby. See [`Geocoder::lookup_hint_country()`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// let place = genom::lookup_hint_country(46.1950, 6.2100, "CH").unwrap();
+/// assert_eq!(place.country_code, "CH");
+/// # }
+/// ```
+pub fn lookup_hint_country(latitude: f64, longitude: f64, country_code: &str) -> Option<Place> {
+    Geocoder::with_current(|geocoder| {
+        geocoder.lookup_hint_country(latitude, longitude, country_code)
+    })
+}
+
+/// Performs reverse geocoding on a slice of coordinates sequentially.
+///
+/// Returns one result per input pair, in the same order. This is equivalent to calling
+/// [`lookup()`] in a loop but resolves the global geocoder only once.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// let coords = [(40.7128, -74.0060), (51.5074, -0.1278), (0.0, -160.0)];
+/// let places = genom::lookup_batch(&coords);
+///
+/// assert_eq!(places.len(), 3);
+/// assert!(places[2].is_none());
+/// # }
+/// ```
+pub fn lookup_batch(coords: &[(f64, f64)]) -> Vec<Option<Place>> {
+    Geocoder::with_current(|geocoder| {
+        coords
+            .iter()
+            .map(|&(lat, lon)| geocoder.lookup(lat, lon))
+            .collect()
+    })
+}
+
+/// Performs reverse geocoding on a slice of coordinates, searching each grid cell once.
+///
+/// Like [`lookup_batch()`], but inputs are grouped by the ~11 km grid cell they fall
+/// into: the nearest place is searched for the first coordinate of each cell and that
+/// result is cloned to every later coordinate in the same cell. On clustered data
+/// (many users in one city) this replaces most nearest-place searches with a hash
+/// lookup.
+///
+/// This is an approximation callers opt into: coordinates near the edge of a cell, or
+/// in cells holding several places, can receive a different place than [`lookup()`]
+/// would return for them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// let coords = [(48.8566, 2.3522), (48.8570, 2.3530), (51.5074, -0.1278)];
+/// let places = genom::lookup_batch_by_cell(&coords);
+///
+/// assert_eq!(places.len(), 3);
+/// assert_eq!(places[0], places[1]);
+/// # }
+/// ```
+pub fn lookup_batch_by_cell(coords: &[(f64, f64)]) -> Vec<Option<Place>> {
+    Geocoder::with_current(|geocoder| {
+        let mut cells: rustc_hash::FxHashMap<(i16, i16), Option<Place>> =
+            rustc_hash::FxHashMap::default();
+        coords
+            .iter()
+            .map(|&(lat, lon)| {
+                cells
+                    .entry(geocoder.grid_key_for(lat, lon))
+                    .or_insert_with(|| geocoder.lookup(lat, lon))
+                    .clone()
+            })
+            .collect()
+    })
+}
+
+/// Performs reverse geocoding on a slice of coordinates in parallel using `rayon`.
+///
+/// Returns one result per input pair, in the same order. Lookups are lock-free reads
+/// on the shared global geocoder, so throughput scales with the number of cores.
+///
+/// The database is initialized before the parallel section starts, so worker threads
+/// never contend on the initialization lock.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// let coords: Vec<(f64, f64)> = (0..10_000)
+///     .map(|i| (40.0 + (i % 100) as f64 * 0.01, -74.0))
+///     .collect();
+/// let places = genom::lookup_par(&coords);
+/// assert_eq!(places.len(), coords.len());
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn lookup_par(coords: &[(f64, f64)]) -> Vec<Option<Place>> {
+    use rayon::prelude::*;
+
+    Geocoder::with_current(|geocoder| {
+        coords
+            .par_iter()
+            .map(|&(lat, lon)| geocoder.lookup(lat, lon))
+            .collect()
+    })
+}
+
+/// Performs reverse geocoding, reporting database initialization failures instead of panicking.
+///
+/// Behaves like [`lookup()`] but returns `Err` if the embedded database cannot be decoded.
+/// The outer `Result` describes the database, the inner `Option` whether a place was found.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), genom::DatabaseError> {
+/// if let Some(place) = genom::try_lookup(52.5200, 13.4050)? {
+///     println!("{}, {}", place.city, place.country_name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_lookup(latitude: f64, longitude: f64) -> Result<Option<Place>, DatabaseError> {
+    Geocoder::try_with_current(|geocoder| geocoder.lookup(latitude, longitude))
+}
+
+/// Initializes the global geocoder eagerly.
+///
+/// The first lookup normally pays the full database initialization cost (~100ms).
+/// Calling `warm_up()` during application startup moves that cost out of the request
+/// path and lets startup fail fast on a corrupted database. Calling it again after a
+/// successful initialization is a cheap no-op.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), genom::DatabaseError> {
+/// // During startup, before accepting traffic
+/// genom::warm_up()?;
+///
+/// // Later lookups no longer incur initialization latency
+/// let place = genom::lookup(40.7128, -74.0060);
+/// # Ok(())
+/// # }
+/// ```
+pub fn warm_up() -> Result<(), DatabaseError> {
+    Geocoder::try_with_current(|_| ())
+}
+
+/// Replaces the database used by the crate-level lookup functions without a restart.
+///
+/// Decodes `data` (in the format written by the builder) into a new geocoder and
+/// atomically swaps it in. Lookups already in progress finish on the previous database,
+/// which is freed once the last of them completes; later calls to [`lookup()`],
+/// [`try_lookup()`], [`lookup_batch()`] and the other crate-level functions use the new
+/// one. On error the current database stays in place.
+///
+/// [`Geocoder::global()`] and geocoders derived from it (such as the
+/// [`CachedGeocoder`](crate::CachedGeocoder) of the `cache` feature) keep using the
+/// embedded database. Combined with `no-build-database`, this also allows supplying
+/// the database at runtime only.
+///
+/// Requires the `reload` feature.
+///
+/// # Errors
+///
+/// Returns a [`DatabaseError`] if the data is truncated or malformed.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data = std::fs::read("places.bin")?;
+/// genom::reload_from_bytes(&data)?;
+/// let place = genom::lookup(48.8566, 2.3522);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "reload")]
+pub fn reload_from_bytes(data: &[u8]) -> Result<(), DatabaseError> {
+    Geocoder::replace_current(Geocoder::from_bytes(data)?);
+    Ok(())
 }
@@ -1,37 +1,275 @@
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = std::env::args().collect();
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+#[derive(Default)]
+struct Options {
+    json: bool,
+    pretty: bool,
+    distance: bool,
+    stdin: bool,
+}
+
+/// Unit accepted by `genom distance --unit`.
+#[derive(Clone, Copy)]
+enum Unit {
+    Kilometers,
+    Miles,
+    NauticalMiles,
+}
+
+impl Unit {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "km" => Some(Self::Kilometers),
+            "mi" => Some(Self::Miles),
+            "nmi" => Some(Self::NauticalMiles),
+            _ => None,
+        }
+    }
+
+    fn convert(self, km: f64) -> f64 {
+        match self {
+            Self::Kilometers => km,
+            Self::Miles => km / 1.609344,
+            Self::NauticalMiles => km / 1.852,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Kilometers => "km",
+            Self::Miles => "mi",
+            Self::NauticalMiles => "nmi",
+        }
+    }
+}
 
-    if args.len() < 3 {
-        eprintln!("Usage: {} <lat> <lon>", args[0]);
-        return Ok(());
-    }
-
-    let lat: f64 = args[1].parse()?;
-    let lon: f64 = args[2].parse()?;
-
-    if let Some(place) = genom::lookup(lat, lon) {
-        println!("{}", place.city);
-        println!("  Region: {}", place.region);
-        println!("  Region Code: {}", place.region_code);
-        println!("  District: {}", place.district);
-        println!("  Country: {} ({})", place.country_name, place.country_code);
-        println!(
-            "  Continent: {} ({})",
-            place.continent_name, place.continent_code
-        );
-        println!("  Postal Code: {}", place.postal_code);
-        println!(
-            "  Timezone: {} ({}) {}",
-            place.timezone, place.timezone_abbr, place.utc_offset_str
-        );
-        println!("  UTC Offset: {} seconds", place.utc_offset);
-        println!("  DST Active: {}", place.dst_active);
-        println!("  Currency: {}", place.currency);
-        println!("  EU Member: {}", place.is_eu);
-        println!("  Coords: {}, {}", place.latitude, place.longitude);
+/// JSON output: the place's fields, optionally followed by the match distance.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonOutput<'a> {
+    #[serde(flatten)]
+    place: &'a genom::Place,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distance_km: Option<f64>,
+}
+
+fn usage(program: &str) {
+    eprintln!(
+        "Usage: {} [--json | --pretty] [--distance] <lat> <lon>",
+        program
+    );
+    eprintln!(
+        "       {} [--json | --pretty] [--distance] <lat,lon>",
+        program
+    );
+    eprintln!(
+        "       {} --stdin [--json | --pretty] [--distance] < coords.txt",
+        program
+    );
+    eprintln!(
+        "       {} distance [--unit km|mi|nmi] <lat1> <lon1> <lat2> <lon2>",
+        program
+    );
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --json      Print the place as JSON");
+    eprintln!("  --pretty    Print the place as indented JSON");
+    eprintln!("  --distance  Also print the distance to the matched place in km");
+    eprintln!("  --stdin     Read one lat,lon pair per line from stdin");
+    eprintln!("  --unit      Unit for the distance subcommand (default: km)");
+}
+
+fn print_place(
+    out: &mut impl Write,
+    place: Option<genom::Place>,
+    distance_km: Option<f64>,
+    options: &Options,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    #[cfg(feature = "serde")]
+    if options.json || options.pretty {
+        let output = place.as_ref().map(|place| JsonOutput {
+            place,
+            distance_km: distance_km.filter(|_| options.distance),
+        });
+        if options.pretty {
+            writeln!(out, "{}", serde_json::to_string_pretty(&output)?)?;
+        } else {
+            writeln!(out, "{}", serde_json::to_string(&output)?)?;
+        }
+        return Ok(place.is_some());
+    }
+
+    if let Some(place) = &place {
+        match (distance_km, options.distance, options.stdin) {
+            (Some(distance_km), true, true) => writeln!(out, "{} ({:.3} km)", place, distance_km)?,
+            (_, _, true) => writeln!(out, "{}", place)?,
+            (Some(distance_km), true, false) => {
+                writeln!(out, "{}", place.format_full())?;
+                writeln!(out, "  Distance: {:.3} km", distance_km)?;
+            }
+            (_, _, false) => writeln!(out, "{}", place.format_full())?,
+        }
     } else {
-        println!("No place found");
+        writeln!(out, "No place found")?;
     }
 
+    Ok(place.is_some())
+}
+
+fn print_error(
+    out: &mut impl Write,
+    message: &str,
+    options: &Options,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if options.json || options.pretty {
+        #[cfg(feature = "serde")]
+        {
+            writeln!(out, "{}", serde_json::json!({ "error": message }))?;
+            return Ok(());
+        }
+    }
+
+    writeln!(out, "error: {}", message)?;
     Ok(())
 }
+
+fn lookup(
+    out: &mut impl Write,
+    location: genom::Location,
+    options: &Options,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let (place, debug) =
+        genom::Geocoder::global().lookup_debug(location.latitude, location.longitude);
+    print_place(out, place, debug.nearest_distance_km, options)
+}
+
+/// Looks up one `lat,lon` pair per stdin line, writing one result line each.
+///
+/// Blank or malformed lines produce an error line instead of stopping the stream.
+fn run_stdin(options: &Options) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut out = io::BufWriter::new(io::stdout().lock());
+    let mut all_found = true;
+
+    for (number, line) in io::stdin().lock().lines().enumerate() {
+        let line = line?;
+        let found = match line.trim() {
+            "" => {
+                print_error(
+                    &mut out,
+                    &format!("line {}: empty line", number + 1),
+                    options,
+                )?;
+                false
+            }
+            text => match text.parse() {
+                Ok(location) => lookup(&mut out, location, options)?,
+                Err(e) => {
+                    print_error(&mut out, &format!("line {}: {}", number + 1, e), options)?;
+                    false
+                }
+            },
+        };
+        all_found &= found;
+    }
+
+    out.flush()?;
+    Ok(all_found)
+}
+
+/// `genom distance`: prints the great-circle distance between two coordinates.
+fn run_distance(program: &str, args: &[String]) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let mut unit = Unit::Kilometers;
+    let mut coordinates = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--unit" => args.next().map(String::as_str),
+            flag if flag.starts_with("--unit=") => Some(&flag["--unit=".len()..]),
+            flag if flag.starts_with("--") => {
+                eprintln!("Unknown option: {}", flag);
+                usage(program);
+                return Ok(ExitCode::from(2));
+            }
+            value => {
+                coordinates.push(value);
+                continue;
+            }
+        };
+        match value.and_then(Unit::parse) {
+            Some(parsed) => unit = parsed,
+            None => {
+                eprintln!("Expected --unit km, mi or nmi");
+                return Ok(ExitCode::from(2));
+            }
+        }
+    }
+
+    let (from, to): (genom::Location, genom::Location) = match coordinates[..] {
+        [from, to] => (from.parse()?, to.parse()?),
+        [lat1, lon1, lat2, lon2] => (
+            format!("{},{}", lat1, lon1).parse()?,
+            format!("{},{}", lat2, lon2).parse()?,
+        ),
+        _ => {
+            usage(program);
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    println!(
+        "{:.3} {}",
+        unit.convert(from.distance_to(&to)),
+        unit.symbol()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("distance") {
+        return run_distance(&args[0], &args[2..]);
+    }
+
+    let mut options = Options::default();
+    let mut coordinates = Vec::new();
+    for arg in &args[1..] {
+        match arg.as_str() {
+            "--json" | "--pretty" if cfg!(not(feature = "serde")) => {
+                eprintln!("Error: {} requires the 'serde' feature.", arg);
+                return Ok(ExitCode::from(2));
+            }
+            "--json" => options.json = true,
+            "--pretty" => options.pretty = true,
+            "--distance" => options.distance = true,
+            "--stdin" => options.stdin = true,
+            flag if flag.starts_with("--") => {
+                eprintln!("Unknown option: {}", flag);
+                usage(&args[0]);
+                return Ok(ExitCode::from(2));
+            }
+            value => coordinates.push(value),
+        }
+    }
+
+    let found = if options.stdin && coordinates.is_empty() {
+        run_stdin(&options)?
+    } else {
+        let location: genom::Location = match (options.stdin, &coordinates[..]) {
+            (false, [pair]) => pair.parse()?,
+            (false, [lat, lon]) => format!("{},{}", lat, lon).parse()?,
+            _ => {
+                usage(&args[0]);
+                return Ok(ExitCode::from(2));
+            }
+        };
+        lookup(&mut io::stdout().lock(), location, &options)?
+    };
+
+    if found {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
@@ -0,0 +1,69 @@
+//! Vectorized nearest-candidate scan for dense grid cells.
+//!
+//! Equirectangular distances are computed for eight candidates at a time in `f32` with
+//! [`wide`], which compiles to SSE/AVX, NEON or WebAssembly SIMD where available and to
+//! scalar code elsewhere. Only the ranking runs in single precision: the caller
+//! recomputes the distance of the winner with the geocoder's metric.
+
+use crate::types::{Database, Location};
+use wide::{f32x8, CmpLt};
+
+/// Cells with fewer candidates than this are scanned with the scalar loop, since filling
+/// the lanes costs more than the vectorized arithmetic saves.
+pub(crate) const MIN_CANDIDATES: usize = 16;
+
+const LANES: usize = 8;
+
+/// Returns the position in `indices` of the place nearest to `location`, or `None` if
+/// `indices` is empty.
+///
+/// Candidates are ranked by equirectangular distance like
+/// [`Location::equirectangular_distance_to()`]. Among equal distances the earliest
+/// position wins, so ties resolve to the lowest index of the ascending cell list.
+pub(crate) fn nearest_in_cell(
+    db: &Database,
+    indices: &[u32],
+    location: &Location,
+) -> Option<usize> {
+    let mut best_score = f32x8::splat(f32::INFINITY);
+    let mut best_position = f32x8::splat(f32::MAX);
+
+    for (chunk_index, chunk) in indices.chunks(LANES).enumerate() {
+        // Unused lanes get an infinite latitude offset and can never win
+        let mut delta_lat = [f32::INFINITY; LANES];
+        let mut delta_lon = [0.0; LANES];
+        let mut mean_lat = [0.0; LANES];
+        let mut position = [f32::MAX; LANES];
+
+        for (lane, &idx) in chunk.iter().enumerate() {
+            let candidate = db.location(idx as usize);
+            let mut lon = candidate.longitude - location.longitude;
+            if lon > 180.0 {
+                lon -= 360.0;
+            } else if lon < -180.0 {
+                lon += 360.0;
+            }
+            delta_lat[lane] = (candidate.latitude - location.latitude) as f32;
+            delta_lon[lane] = lon as f32;
+            mean_lat[lane] = ((candidate.latitude + location.latitude) / 2.0) as f32;
+            position[lane] = (chunk_index * LANES + lane) as f32;
+        }
+
+        let y = f32x8::from(delta_lat);
+        let x = f32x8::from(delta_lon) * f32x8::from(mean_lat).to_radians().cos();
+        let score = x.mul_add(x, y * y);
+
+        // Strictly less, so an earlier chunk keeps its lane on a tie
+        let closer = score.cmp_lt(best_score);
+        best_score = closer.blend(score, best_score);
+        best_position = closer.blend(f32x8::from(position), best_position);
+    }
+
+    best_score
+        .to_array()
+        .into_iter()
+        .zip(best_position.to_array())
+        .filter(|(score, _)| score.is_finite())
+        .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+        .map(|(_, position)| position as usize)
+}
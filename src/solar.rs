@@ -0,0 +1,63 @@
+//! Sunrise and sunset computation.
+//!
+//! Implements the NOAA solar position algorithm (as used in the NOAA Solar Calculator
+//! spreadsheets). Results are accurate to about a minute for latitudes within the polar
+//! circles; atmospheric refraction is accounted for with the standard 0.833° correction.
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+/// Solar zenith angle at sunrise and sunset, including refraction and the sun's radius.
+const SUNRISE_ZENITH: f64 = 90.833;
+
+/// Computes sunrise and sunset for the given coordinates and (local) date.
+///
+/// Returns `None` during polar day or polar night, when the sun does not cross the
+/// horizon on that date.
+pub(crate) fn sun_times(
+    latitude: f64,
+    longitude: f64,
+    date: NaiveDate,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let midnight = date.and_hms_opt(0, 0, 0)?.and_utc();
+    let julian_day = midnight.timestamp() as f64 / 86400.0 + 2440587.5;
+    // Evaluate the solar position at the approximate local solar noon.
+    let t = (julian_day + 0.5 - longitude / 360.0 - 2451545.0) / 36525.0;
+
+    let mean_long = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let m = mean_anomaly.to_radians();
+    let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_long = (mean_long + center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_long.sin()).asin();
+
+    let y = (obliquity / 2.0).tan().powi(2);
+    let l0 = mean_long.to_radians();
+    let equation_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+        .to_degrees();
+
+    let lat = latitude.to_radians();
+    let cos_hour_angle = SUNRISE_ZENITH.to_radians().cos() / (lat.cos() * declination.cos())
+        - lat.tan() * declination.tan();
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+    let hour_angle = cos_hour_angle.acos().to_degrees();
+
+    let solar_noon = 720.0 - 4.0 * longitude - equation_of_time;
+    let at = |minutes: f64| midnight + TimeDelta::seconds((minutes * 60.0).round() as i64);
+    Some((
+        at(solar_noon - 4.0 * hour_angle),
+        at(solar_noon + 4.0 * hour_angle),
+    ))
+}
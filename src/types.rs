@@ -3,17 +3,36 @@
 //! This module defines the fundamental types used throughout the library:
 //!
 //! - [`Place`] - Enriched output with complete geographic context
+//! - [`PlaceRef`] - Borrowed, allocation-free view of a place
+//! - [`PlaceField`] - Runtime selector for a single [`Place`] field
+//! - [`AlternateNames`] - Borrowed view of a place's localized names
 //! - [`Location`] - Simple coordinate pair with distance calculations
+//! - [`Utm`] - Universal Transverse Mercator coordinate and its [`Hemisphere`]
+//! - [`DistanceMetric`] - Pluggable distance model ([`Haversine`], [`Ellipsoidal`],
+//!   [`Equirectangular`])
 //! - [`CompactPlace`] - Compressed storage format using string table indices
 //! - [`Database`] - Complete spatial database with grid index
+//! - [`Grid`] - Spatial grid index in compressed sparse row layout
+//! - [`DatabaseStats`] - Summary statistics of a loaded database
+//! - [`DatabaseInfo`] - Build metadata from the database header
+//! - [`LookupDebug`] - Search statistics of a single lookup
+//! - [`LookupOptions`] - Per-request search settings
 
 #![warn(missing_docs)]
 
+use crate::error::ParseLocationError;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use chrono_tz::Tz;
+use rustc_hash::FxHashMap;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 /// The enriched output type containing complete geographic context for a location.
 ///
-/// This struct is returned by [`lookup()`](crate::lookup) and contains 18 fields
+/// This struct is returned by [`lookup()`](crate::lookup) and contains 30 fields
 /// providing comprehensive information about a geographic location.
 ///
 /// # Examples
@@ -29,16 +48,30 @@ use serde::{Deserialize, Serialize};
 /// println!("EU Member: {}", place.is_eu);
 /// # }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct Place {
     /// City or locality name (e.g., "New York", "Tokyo", "Paris")
     pub city: String,
+    /// ASCII-only form of the city name (e.g., "Sao Paulo" for "São Paulo", "Zurich"
+    /// for "Zürich"), as provided by GeoNames
+    pub city_ascii: String,
     /// State, province, or administrative region full name (e.g., "California", "Tokyo", "Île-de-France")
     pub region: String,
     /// ISO 3166-2 region code (e.g., "CA" for California, "13" for Tokyo)
     pub region_code: String,
     /// County, district, or sub-region (e.g., "Los Angeles County", "Chiyoda")
     pub district: String,
+    /// Finer administrative subdivision such as a commune or municipality
+    /// (empty when GeoNames has no admin3/admin4 data for the place)
+    pub subdistrict: String,
+    /// GeoNames feature code of the place (e.g., "PPLC" for a national capital,
+    /// "PPLA" for a first-order administrative seat, "PPL" for other populated places)
+    pub feature_code: String,
+    /// Date GeoNames last modified the entry, if known
+    #[cfg_attr(feature = "bincode", bincode(with_serde))]
+    pub modified: Option<NaiveDate>,
     /// ISO 3166-1 alpha-2 country code (e.g., "US", "JP", "FR")
     pub country_code: String,
     /// Full country name (e.g., "United States", "Japan", "France")
@@ -51,6 +84,9 @@ pub struct Place {
     pub timezone_abbr: String,
     /// Current UTC offset in seconds (e.g., -18000 for UTC-5, 32400 for UTC+9)
     pub utc_offset: i32,
+    /// Standard-time UTC offset in seconds, without DST (e.g., 3600 for Europe/Paris
+    /// all year, while `utc_offset` is 7200 in summer)
+    pub utc_offset_std: i32,
     /// Formatted UTC offset string (e.g., "UTC-5", "UTC+9", "UTC+5:30")
     pub utc_offset_str: String,
     /// Precise latitude coordinate in decimal degrees (-90 to 90)
@@ -59,77 +95,1837 @@ pub struct Place {
     pub longitude: f64,
     /// ISO 4217 currency code (e.g., "USD", "JPY", "EUR")
     pub currency: String,
+    /// Local currency symbol (e.g., "$", "¥", "€"). Several currencies share "$".
+    pub currency_symbol: String,
+    /// Number of decimal places (ISO 4217 minor unit) used for amounts in the currency
+    /// (e.g., 2 for USD, 0 for JPY, 3 for BHD); 2 if the currency is unknown
+    pub currency_decimals: u8,
+    /// All official ISO 4217 currencies of the country, primary ([`currency`](Place::currency))
+    /// first (e.g., `["PAB", "USD"]` for Panama). Empty if the currency is unknown.
+    pub currencies: Vec<String>,
     /// Two-letter continent code (e.g., "NA" for North America, "AS" for Asia, "EU" for Europe)
     pub continent_code: String,
     /// Full continent name (e.g., "North America", "Asia", "Europe")
     pub continent_name: String,
+    /// UN M49 subregion code (e.g., "155" for Western Europe, "029" for the Caribbean).
+    /// Uses the M49 intermediate region where one exists.
+    pub subregion_code: String,
+    /// UN M49 subregion name (e.g., "Western Europe", "South-eastern Asia", "Caribbean")
+    pub subregion_name: String,
     /// Whether the location is in a European Union member state
     pub is_eu: bool,
+    /// Whether the country has no sea coast (e.g., Switzerland, Bolivia, Mongolia)
+    pub is_landlocked: bool,
     /// Whether daylight saving time is currently active for this location
     pub dst_active: bool,
+    /// Localized names as `(language, name)` pairs (e.g., `("de", "München")`). Only
+    /// languages whose name differs from `city` are listed.
+    pub alternate_names: Vec<(String, String)>,
+}
+
+impl Place {
+    /// Returns the coordinates of the place as a [`Location`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::Location;
+    ///
+    /// let place = genom::lookup(48.8566, 2.3522).unwrap();
+    /// let km = place.location().distance_to(&Location::new(52.5200, 13.4050));
+    /// println!("{} is {:.0} km from Berlin", place.city, km);
+    /// # }
+    /// ```
+    pub fn location(&self) -> Location {
+        Location::new(self.latitude, self.longitude)
+    }
+
+    /// Formats the place as a multi-line, human-readable report of all fields.
+    ///
+    /// This is the output format of the bundled `genom` CLI. For a concise single-line
+    /// form suitable for logs, use the [`Display`](fmt::Display) implementation instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(40.7128, -74.0060).unwrap();
+    /// println!("{}", place.format_full());
+    /// // New York
+    /// //   Region: New York
+    /// //   ...
+    /// # }
+    /// ```
+    pub fn format_full(&self) -> String {
+        [
+            self.city.clone(),
+            format!("  Region: {}", self.region),
+            format!("  Region Code: {}", self.region_code),
+            format!("  District: {}", self.district),
+            format!("  Subdistrict: {}", self.subdistrict),
+            format!("  Country: {} ({})", self.country_name, self.country_code),
+            format!(
+                "  Continent: {} ({})",
+                self.continent_name, self.continent_code
+            ),
+            format!(
+                "  Subregion: {} ({})",
+                self.subregion_name, self.subregion_code
+            ),
+            format!("  Postal Code: {}", self.postal_code),
+            format!(
+                "  Timezone: {} ({}) {}",
+                self.timezone, self.timezone_abbr, self.utc_offset_str
+            ),
+            format!(
+                "  UTC Offset: {} seconds (standard {} seconds)",
+                self.utc_offset, self.utc_offset_std
+            ),
+            format!("  DST Active: {}", self.dst_active),
+            format!("  Currency: {} ({})", self.currency, self.currency_symbol),
+            format!("  EU Member: {}", self.is_eu),
+            format!("  Landlocked: {}", self.is_landlocked),
+            format!("  Coords: {}, {}", self.latitude, self.longitude),
+        ]
+        .join("\n")
+    }
+
+    /// Returns the city name in the given language, if the database has one.
+    ///
+    /// `lang` is an ISO 639 language code as used by GeoNames (e.g., `"de"`, `"ja"`),
+    /// compared case-insensitively. Returns `None` when no localized name is stored, in
+    /// which case [`city`](Place::city) is the name to display.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(48.1351, 11.5820).unwrap();
+    /// let name = place.name_in("de").unwrap_or_else(|| place.city.clone());
+    /// println!("{}", name); // München
+    /// # }
+    /// ```
+    pub fn name_in(&self, lang: &str) -> Option<String> {
+        self.alternate_names
+            .iter()
+            .find(|(l, _)| l.eq_ignore_ascii_case(lang))
+            .map(|(_, name)| name.clone())
+    }
+
+    /// Returns `true` if the place is a national capital or seat of government
+    /// (feature code `PPLC` or `PPLG`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// assert!(genom::lookup(52.5200, 13.4050).unwrap().is_national_capital());
+    /// assert!(!genom::lookup(34.0522, -118.2437).unwrap().is_national_capital());
+    /// # }
+    /// ```
+    pub fn is_national_capital(&self) -> bool {
+        is_national_capital(&self.feature_code)
+    }
+
+    /// Returns `true` if the place is the seat of a first- or second-order
+    /// administrative division, such as a state capital (feature code `PPLA` or
+    /// `PPLA2`).
+    ///
+    /// National capitals are not counted, even though they usually also seat a region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// // Sacramento is California's capital; Los Angeles is just a big city
+    /// assert!(genom::lookup(38.5816, -121.4944).unwrap().is_regional_capital());
+    /// assert!(!genom::lookup(34.0522, -118.2437).unwrap().is_regional_capital());
+    /// # }
+    /// ```
+    pub fn is_regional_capital(&self) -> bool {
+        is_regional_capital(&self.feature_code)
+    }
+
+    /// Returns the administrative hierarchy of the place, from country down to city.
+    ///
+    /// Levels are country, region, district, subdistrict and city, in that order.
+    /// Levels with an empty name are skipped. Only the country and region carry a code;
+    /// the other levels have an empty `code`. Useful for breadcrumbs and hierarchical
+    /// location keys.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::AdminKind;
+    ///
+    /// let place = genom::lookup(34.0522, -118.2437).unwrap();
+    /// let levels = place.admin_path();
+    /// let path: Vec<&str> = levels.iter().map(|level| level.name.as_str()).collect();
+    /// println!("{}", path.join(" > "));
+    /// // United States > California > Los Angeles County > Los Angeles
+    ///
+    /// assert_eq!(levels[0].kind, AdminKind::Country);
+    /// assert_eq!(levels[0].code, "US");
+    /// # }
+    /// ```
+    pub fn admin_path(&self) -> Vec<AdminLevel> {
+        [
+            (
+                AdminKind::Country,
+                self.country_name.as_str(),
+                self.country_code.as_str(),
+            ),
+            (AdminKind::Region, &self.region, &self.region_code),
+            (AdminKind::District, &self.district, ""),
+            (AdminKind::Subdistrict, &self.subdistrict, ""),
+            (AdminKind::City, &self.city, ""),
+        ]
+        .into_iter()
+        .filter(|(_, name, _)| !name.is_empty())
+        .map(|(kind, name, code)| AdminLevel {
+            kind,
+            name: name.to_string(),
+            code: code.to_string(),
+        })
+        .collect()
+    }
+
+    /// Returns the value of `field` as a string.
+    ///
+    /// Strings are returned as stored, numbers and booleans are formatted with
+    /// [`Display`](fmt::Display), [`modified`](Place::modified) is formatted as
+    /// `YYYY-MM-DD` (empty if unknown) and [`currencies`](Place::currencies) is joined
+    /// with commas.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use genom::PlaceField;
+    ///
+    /// let place = genom::lookup(48.8566, 2.3522).unwrap();
+    /// let columns = [PlaceField::City, PlaceField::CountryCode, PlaceField::IsEu];
+    /// let row: Vec<String> = columns.iter().map(|&field| place.get(field)).collect();
+    /// assert_eq!(row, ["Paris", "FR", "true"]);
+    /// # }
+    /// ```
+    pub fn get(&self, field: PlaceField) -> String {
+        match field {
+            PlaceField::City => self.city.clone(),
+            PlaceField::CityAscii => self.city_ascii.clone(),
+            PlaceField::Region => self.region.clone(),
+            PlaceField::RegionCode => self.region_code.clone(),
+            PlaceField::District => self.district.clone(),
+            PlaceField::Subdistrict => self.subdistrict.clone(),
+            PlaceField::FeatureCode => self.feature_code.clone(),
+            PlaceField::Modified => self
+                .modified
+                .map_or_else(String::new, |date| date.to_string()),
+            PlaceField::CountryCode => self.country_code.clone(),
+            PlaceField::CountryName => self.country_name.clone(),
+            PlaceField::PostalCode => self.postal_code.clone(),
+            PlaceField::Timezone => self.timezone.clone(),
+            PlaceField::TimezoneAbbr => self.timezone_abbr.clone(),
+            PlaceField::UtcOffset => self.utc_offset.to_string(),
+            PlaceField::UtcOffsetStd => self.utc_offset_std.to_string(),
+            PlaceField::UtcOffsetStr => self.utc_offset_str.clone(),
+            PlaceField::Latitude => self.latitude.to_string(),
+            PlaceField::Longitude => self.longitude.to_string(),
+            PlaceField::Currency => self.currency.clone(),
+            PlaceField::CurrencySymbol => self.currency_symbol.clone(),
+            PlaceField::CurrencyDecimals => self.currency_decimals.to_string(),
+            PlaceField::Currencies => self.currencies.join(","),
+            PlaceField::ContinentCode => self.continent_code.clone(),
+            PlaceField::ContinentName => self.continent_name.clone(),
+            PlaceField::SubregionCode => self.subregion_code.clone(),
+            PlaceField::SubregionName => self.subregion_name.clone(),
+            PlaceField::IsEu => self.is_eu.to_string(),
+            PlaceField::IsLandlocked => self.is_landlocked.to_string(),
+            PlaceField::DstActive => self.dst_active.to_string(),
+        }
+    }
+
+    /// Returns the next instant at which the UTC offset of the place's timezone changes.
+    ///
+    /// Looks up to one year ahead of the current time. Returns `None` for zones without
+    /// DST (or other scheduled offset changes) and for unknown timezone identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(52.5200, 13.4050).unwrap();
+    /// if let Some(at) = place.next_dst_transition() {
+    ///     println!("Clocks in {} change at {}", place.city, at);
+    /// }
+    /// # }
+    /// ```
+    pub fn next_dst_transition(&self) -> Option<DateTime<Utc>> {
+        let tz: Tz = self.timezone.parse().ok()?;
+        crate::enrichment::next_offset_change(&tz, Utc::now())
+    }
+
+    /// Returns the current time at the place.
+    ///
+    /// The offset is resolved from the IANA timezone for the current instant, so it is
+    /// correct even if DST started or ended since the lookup. If the timezone identifier
+    /// is unknown, the stored [`utc_offset`](Place::utc_offset) is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(35.6762, 139.6503).unwrap();
+    /// println!("It is {} in {}", place.local_time().format("%H:%M"), place.city);
+    /// # }
+    /// ```
+    pub fn local_time(&self) -> DateTime<FixedOffset> {
+        self.to_local(Utc::now())
+    }
+
+    /// Formats the current time at the place with a `chrono` format string.
+    ///
+    /// Shorthand for `place.local_time().format(fmt).to_string()`; see
+    /// [`chrono::format::strftime`] for the supported specifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(40.7128, -74.0060).unwrap();
+    /// println!("{}", place.format_local("%Y-%m-%d %H:%M %:z"));
+    /// # }
+    /// ```
+    pub fn format_local(&self, fmt: &str) -> String {
+        self.local_time().format(fmt).to_string()
+    }
+
+    /// Computes approximate sunrise and sunset at the place on the given local date.
+    ///
+    /// Uses the NOAA solar position algorithm on the place's coordinates; times are
+    /// accurate to about a minute and returned in the place's timezone. Returns `None`
+    /// during polar day or polar night, when the sun does not rise or set that day.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// use chrono::NaiveDate;
+    ///
+    /// let place = genom::lookup(48.8566, 2.3522).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+    /// if let Some((sunrise, sunset)) = place.sun_times(date) {
+    ///     println!("{} - {}", sunrise.format("%H:%M"), sunset.format("%H:%M"));
+    /// }
+    /// # }
+    /// ```
+    pub fn sun_times(
+        &self,
+        date: NaiveDate,
+    ) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        let (sunrise, sunset) = crate::solar::sun_times(self.latitude, self.longitude, date)?;
+        Some((self.to_local(sunrise), self.to_local(sunset)))
+    }
+
+    /// Encodes the place as MessagePack.
+    ///
+    /// Fields are written positionally (as a MessagePack array, without field names),
+    /// which keeps the encoding compact but means both ends must use the same version of
+    /// this crate. Decode with [`Place::from_msgpack()`].
+    ///
+    /// Requires the `msgpack` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(48.8566, 2.3522).unwrap();
+    /// let bytes = place.to_msgpack();
+    /// assert_eq!(genom::Place::from_msgpack(&bytes).unwrap(), place);
+    /// # }
+    /// ```
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        rmp_serde::to_vec(self).expect("a Place always serializes to MessagePack")
+    }
+
+    /// Decodes a place encoded with [`Place::to_msgpack()`].
+    ///
+    /// Requires the `msgpack` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a MessagePack-encoded place.
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(data: &[u8]) -> Result<Place, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(data)
+    }
+
+    /// Encodes the place with bincode.
+    ///
+    /// The configuration is pinned to little-endian byte order, variable-length integer
+    /// encoding and no size limit, independent of bincode's defaults. Fields are written
+    /// in declaration order without names, so a blob decodes with any version of this
+    /// crate whose `Place` has the same fields. Decode with [`Place::decode()`].
+    ///
+    /// Requires the `bincode` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() {
+    /// let place = genom::lookup(48.8566, 2.3522).unwrap();
+    /// let bytes = place.encode();
+    /// assert_eq!(genom::Place::decode(&bytes).unwrap(), place);
+    /// # }
+    /// ```
+    #[cfg(feature = "bincode")]
+    pub fn encode(&self) -> Vec<u8> {
+        bincode::encode_to_vec(self, place_bincode_config())
+            .expect("a Place always encodes with bincode")
+    }
+
+    /// Decodes a place encoded with [`Place::encode()`].
+    ///
+    /// Requires the `bincode` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a bincode-encoded place.
+    #[cfg(feature = "bincode")]
+    pub fn decode(data: &[u8]) -> Result<Place, bincode::error::DecodeError> {
+        bincode::decode_from_slice(data, place_bincode_config()).map(|(place, _)| place)
+    }
+
+    /// Converts an instant to the place's local time, resolving the offset from the IANA
+    /// timezone and falling back to the stored `utc_offset`.
+    fn to_local(&self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self.timezone.parse::<Tz>() {
+            Ok(tz) => instant.with_timezone(&tz).fixed_offset(),
+            Err(_) => {
+                let offset = FixedOffset::east_opt(self.utc_offset)
+                    .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+                instant.with_timezone(&offset)
+            }
+        }
+    }
+
+    /// Returns the fields that identify this place, with coordinates quantized back to
+    /// the database's fixed-point representation.
+    fn identity(&self) -> (&str, &str, &str, &str, &str, &str, &str, &str, i32, i32) {
+        (
+            &self.city,
+            &self.region,
+            &self.region_code,
+            &self.district,
+            &self.subdistrict,
+            &self.country_code,
+            &self.postal_code,
+            &self.timezone,
+            (self.latitude * 100000.0).round() as i32,
+            (self.longitude * 100000.0).round() as i32,
+        )
+    }
+}
+
+/// Two places are equal when they describe the same database entry.
+///
+/// Equality and hashing consider the stored fields (`city`, `region`, `region_code`,
+/// `district`, `subdistrict`, `country_code`, `postal_code`, `timezone`) and the
+/// coordinates rounded to 5 decimal places. Coordinates originate from fixed-point
+/// integers with that precision, so the rounding is lossless for places returned by a
+/// lookup.
+///
+/// Derived fields are ignored: the country, currency, continent, subregion and EU
+/// fields are functions of `country_code`; `city_ascii`, `feature_code`, `modified` and
+/// the alternate names belong to the same entry; and the time fields (`timezone_abbr`,
+/// `utc_offset`, `utc_offset_std`, `utc_offset_str`, `dst_active`) depend on when the
+/// lookup ran. Two lookups of the
+/// same place on either side of a DST transition therefore still compare equal.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// use std::collections::HashSet;
+///
+/// let places: HashSet<genom::Place> = [(48.8566, 2.3522), (48.8567, 2.3523)]
+///     .iter()
+///     .filter_map(|&(lat, lon)| genom::lookup(lat, lon))
+///     .collect();
+/// assert_eq!(places.len(), 1);
+/// # }
+/// ```
+impl PartialEq for Place {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for Place {}
+
+impl Hash for Place {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+/// One level of a place's administrative hierarchy, as returned by
+/// [`Place::admin_path()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AdminLevel {
+    /// Which level of the hierarchy this is
+    pub kind: AdminKind,
+    /// Name of the division (e.g., "France", "Île-de-France", "Paris")
+    pub name: String,
+    /// Code of the division: the ISO 3166-1 code for countries, the region code for
+    /// regions, and empty for finer levels
+    pub code: String,
+}
+
+/// The kind of an [`AdminLevel`], from coarsest to finest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AdminKind {
+    /// Country
+    Country,
+    /// State, province or other first-order division ([`Place::region`])
+    Region,
+    /// County or other second-order division ([`Place::district`])
+    District,
+    /// Commune, municipality or finer division ([`Place::subdistrict`])
+    Subdistrict,
+    /// The place itself ([`Place::city`])
+    City,
+}
+
+/// A field of [`Place`], for selecting fields at runtime with [`Place::get()`].
+///
+/// Lets generic tooling such as CSV exporters or output templates pick columns without
+/// hardcoding field names. [`PlaceField::ALL`] lists every field in declaration order and
+/// [`PlaceField::name()`] gives the matching struct field name, e.g. for column headers.
+/// [`Place::alternate_names`] has no single string value and is not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlaceField {
+    /// City name in UTF-8 ([`Place::city`])
+    City,
+    /// ASCII city name ([`Place::city_ascii`])
+    CityAscii,
+    /// Region name ([`Place::region`])
+    Region,
+    /// Region code ([`Place::region_code`])
+    RegionCode,
+    /// District name ([`Place::district`])
+    District,
+    /// Subdistrict name ([`Place::subdistrict`])
+    Subdistrict,
+    /// GeoNames feature code ([`Place::feature_code`])
+    FeatureCode,
+    /// Last modification date as `YYYY-MM-DD`, empty if unknown ([`Place::modified`])
+    Modified,
+    /// ISO 3166-1 alpha-2 country code ([`Place::country_code`])
+    CountryCode,
+    /// Country name ([`Place::country_name`])
+    CountryName,
+    /// Postal code ([`Place::postal_code`])
+    PostalCode,
+    /// IANA timezone identifier ([`Place::timezone`])
+    Timezone,
+    /// Timezone abbreviation ([`Place::timezone_abbr`])
+    TimezoneAbbr,
+    /// UTC offset in seconds ([`Place::utc_offset`])
+    UtcOffset,
+    /// Standard-time UTC offset in seconds ([`Place::utc_offset_std`])
+    UtcOffsetStd,
+    /// Formatted UTC offset ([`Place::utc_offset_str`])
+    UtcOffsetStr,
+    /// Latitude ([`Place::latitude`])
+    Latitude,
+    /// Longitude ([`Place::longitude`])
+    Longitude,
+    /// Primary currency code ([`Place::currency`])
+    Currency,
+    /// Currency symbol ([`Place::currency_symbol`])
+    CurrencySymbol,
+    /// Currency minor unit digits ([`Place::currency_decimals`])
+    CurrencyDecimals,
+    /// All currency codes, comma-separated ([`Place::currencies`])
+    Currencies,
+    /// Continent code ([`Place::continent_code`])
+    ContinentCode,
+    /// Continent name ([`Place::continent_name`])
+    ContinentName,
+    /// UN M49 subregion code ([`Place::subregion_code`])
+    SubregionCode,
+    /// UN M49 subregion name ([`Place::subregion_name`])
+    SubregionName,
+    /// EU membership, `true` or `false` ([`Place::is_eu`])
+    IsEu,
+    /// Landlocked country, `true` or `false` ([`Place::is_landlocked`])
+    IsLandlocked,
+    /// Whether DST is active, `true` or `false` ([`Place::dst_active`])
+    DstActive,
+}
+
+impl PlaceField {
+    /// Every field, in the declaration order of [`Place`].
+    pub const ALL: &'static [PlaceField] = &[
+        PlaceField::City,
+        PlaceField::CityAscii,
+        PlaceField::Region,
+        PlaceField::RegionCode,
+        PlaceField::District,
+        PlaceField::Subdistrict,
+        PlaceField::FeatureCode,
+        PlaceField::Modified,
+        PlaceField::CountryCode,
+        PlaceField::CountryName,
+        PlaceField::PostalCode,
+        PlaceField::Timezone,
+        PlaceField::TimezoneAbbr,
+        PlaceField::UtcOffset,
+        PlaceField::UtcOffsetStd,
+        PlaceField::UtcOffsetStr,
+        PlaceField::Latitude,
+        PlaceField::Longitude,
+        PlaceField::Currency,
+        PlaceField::CurrencySymbol,
+        PlaceField::CurrencyDecimals,
+        PlaceField::Currencies,
+        PlaceField::ContinentCode,
+        PlaceField::ContinentName,
+        PlaceField::SubregionCode,
+        PlaceField::SubregionName,
+        PlaceField::IsEu,
+        PlaceField::IsLandlocked,
+        PlaceField::DstActive,
+    ];
+
+    /// Returns the name of the corresponding [`Place`] field (e.g., `"country_code"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            PlaceField::City => "city",
+            PlaceField::CityAscii => "city_ascii",
+            PlaceField::Region => "region",
+            PlaceField::RegionCode => "region_code",
+            PlaceField::District => "district",
+            PlaceField::Subdistrict => "subdistrict",
+            PlaceField::FeatureCode => "feature_code",
+            PlaceField::Modified => "modified",
+            PlaceField::CountryCode => "country_code",
+            PlaceField::CountryName => "country_name",
+            PlaceField::PostalCode => "postal_code",
+            PlaceField::Timezone => "timezone",
+            PlaceField::TimezoneAbbr => "timezone_abbr",
+            PlaceField::UtcOffset => "utc_offset",
+            PlaceField::UtcOffsetStd => "utc_offset_std",
+            PlaceField::UtcOffsetStr => "utc_offset_str",
+            PlaceField::Latitude => "latitude",
+            PlaceField::Longitude => "longitude",
+            PlaceField::Currency => "currency",
+            PlaceField::CurrencySymbol => "currency_symbol",
+            PlaceField::CurrencyDecimals => "currency_decimals",
+            PlaceField::Currencies => "currencies",
+            PlaceField::ContinentCode => "continent_code",
+            PlaceField::ContinentName => "continent_name",
+            PlaceField::SubregionCode => "subregion_code",
+            PlaceField::SubregionName => "subregion_name",
+            PlaceField::IsEu => "is_eu",
+            PlaceField::IsLandlocked => "is_landlocked",
+            PlaceField::DstActive => "dst_active",
+        }
+    }
+}
+
+/// Formats the place as a concise single line: city, region, country and timezone.
+///
+/// An empty region is omitted.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// let place = genom::lookup(40.7128, -74.0060).unwrap();
+/// println!("{}", place);
+/// // New York, New York, United States (US) [America/New_York UTC-5]
+/// # }
+/// ```
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.city)?;
+        if !self.region.is_empty() {
+            write!(f, ", {}", self.region)?;
+        }
+        write!(
+            f,
+            ", {} ({}) [{} {}]",
+            self.country_name, self.country_code, self.timezone, self.utc_offset_str
+        )
+    }
+}
+
+/// A borrowed, allocation-free view of a place.
+///
+/// Returned by [`Geocoder::lookup_ref()`](crate::Geocoder::lookup_ref). String fields
+/// borrow directly from the database's string table or from the static enrichment
+/// tables, so producing a `PlaceRef` performs no heap allocation.
+///
+/// The time-dependent fields of [`Place`] (`timezone_abbr`, `utc_offset`,
+/// `utc_offset_std`, `utc_offset_str`, `dst_active`) are not included, since they must be computed for
+/// the current instant. Call [`PlaceRef::to_owned`] to obtain a full [`Place`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// use genom::Geocoder;
+///
+/// let geocoder = Geocoder::global();
+/// if let Some(place) = geocoder.lookup_ref(52.5200, 13.4050) {
+///     println!("{}, {}", place.city, place.country_name);
+///     let owned: genom::Place = place.to_owned();
+///     println!("{}", owned.timezone_abbr);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaceRef<'a> {
+    /// City or locality name
+    pub city: &'a str,
+    /// ASCII-only form of the city name
+    pub city_ascii: &'a str,
+    /// State, province, or administrative region full name
+    pub region: &'a str,
+    /// ISO 3166-2 region code
+    pub region_code: &'a str,
+    /// County, district, or sub-region
+    pub district: &'a str,
+    /// Finer administrative subdivision (commune, municipality)
+    pub subdistrict: &'a str,
+    /// GeoNames feature code
+    pub feature_code: &'a str,
+    /// Date GeoNames last modified the entry
+    pub modified: Option<NaiveDate>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: &'a str,
+    /// Full country name
+    pub country_name: &'a str,
+    /// Postal or ZIP code
+    pub postal_code: &'a str,
+    /// IANA timezone identifier
+    pub timezone: &'a str,
+    /// Latitude coordinate in decimal degrees
+    pub latitude: f64,
+    /// Longitude coordinate in decimal degrees
+    pub longitude: f64,
+    /// ISO 4217 currency code
+    pub currency: &'a str,
+    /// Local currency symbol
+    pub currency_symbol: &'a str,
+    /// Number of decimal places used for amounts in the currency
+    pub currency_decimals: u8,
+    /// All official currencies of the country, primary first
+    pub currencies: &'a [&'a str],
+    /// Two-letter continent code
+    pub continent_code: &'a str,
+    /// Full continent name
+    pub continent_name: &'a str,
+    /// UN M49 subregion code
+    pub subregion_code: &'a str,
+    /// UN M49 subregion name
+    pub subregion_name: &'a str,
+    /// Whether the location is in a European Union member state
+    pub is_eu: bool,
+    /// Whether the country has no sea coast
+    pub is_landlocked: bool,
+    /// Localized names of the city
+    pub alternate_names: AlternateNames<'a>,
+}
+
+impl<'a> PlaceRef<'a> {
+    /// Returns the city name in the given language, if the database has one.
+    ///
+    /// See [`Place::name_in`].
+    pub fn name_in(&self, lang: &str) -> Option<&'a str> {
+        self.alternate_names.get(lang)
+    }
+
+    /// Returns `true` if the place is a national capital or seat of government.
+    ///
+    /// See [`Place::is_national_capital`].
+    pub fn is_national_capital(&self) -> bool {
+        is_national_capital(self.feature_code)
+    }
+
+    /// Returns `true` if the place seats a first- or second-order administrative division.
+    ///
+    /// See [`Place::is_regional_capital`].
+    pub fn is_regional_capital(&self) -> bool {
+        is_regional_capital(self.feature_code)
+    }
+
+    /// Converts the borrowed view into an owned [`Place`], computing the timezone fields
+    /// for the current instant.
+    pub fn to_owned(&self) -> Place {
+        crate::enrichment::enrich_place(crate::enrichment::PlaceInput {
+            city: self.city,
+            city_ascii: self.city_ascii,
+            region: self.region,
+            region_code: self.region_code,
+            district: self.district,
+            subdistrict: self.subdistrict,
+            country_code: self.country_code,
+            postal_code: self.postal_code,
+            timezone: self.timezone,
+            feature_code: self.feature_code,
+            modified: self.modified,
+            latitude: self.latitude,
+            longitude: self.longitude,
+            alternate_names: self.alternate_names,
+        })
+    }
+}
+
+/// A borrowed view of a place's localized names.
+///
+/// Entries are `(language, name)` index pairs into the database's string table, so the
+/// names share storage with every other interned string.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AlternateNames<'a> {
+    strings: &'a [String],
+    entries: &'a [(u32, u32)],
+}
+
+impl<'a> AlternateNames<'a> {
+    /// Creates a view over `entries`, resolving indices against `strings`.
+    pub fn new(strings: &'a [String], entries: &'a [(u32, u32)]) -> Self {
+        Self { strings, entries }
+    }
+
+    /// Returns the name for `lang` (case-insensitive), if present.
+    pub fn get(&self, lang: &str) -> Option<&'a str> {
+        self.iter()
+            .find(|(l, _)| l.eq_ignore_ascii_case(lang))
+            .map(|(_, name)| name)
+    }
+
+    /// Iterates over `(language, name)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        let strings = self.strings;
+        self.entries.iter().map(move |&(lang, name)| {
+            (
+                strings[lang as usize].as_str(),
+                strings[name as usize].as_str(),
+            )
+        })
+    }
+
+    /// Returns the number of localized names.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no localized names.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl From<PlaceRef<'_>> for Place {
+    fn from(place: PlaceRef<'_>) -> Self {
+        place.to_owned()
+    }
 }
 
 /// A coordinate pair with distance calculation capabilities.
 ///
-/// This is a simple wrapper around latitude and longitude coordinates that provides
-/// utility methods for geographic calculations.
-#[derive(Debug, Clone, Copy)]
-pub struct Location {
-    /// Latitude in decimal degrees (-90 to 90)
-    pub latitude: f64,
-    /// Longitude in decimal degrees (-180 to 180)
-    pub longitude: f64,
+/// This is a simple wrapper around latitude and longitude coordinates that provides
+/// utility methods for geographic calculations.
+#[derive(Debug, Clone, Copy)]
+pub struct Location {
+    /// Latitude in decimal degrees (-90 to 90)
+    pub latitude: f64,
+    /// Longitude in decimal degrees (-180 to 180)
+    pub longitude: f64,
+}
+
+impl Location {
+    /// Constructs a new Location from coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let loc = Location::new(40.7128, -74.0060);
+    /// assert_eq!(loc.latitude, 40.7128);
+    /// assert_eq!(loc.longitude, -74.0060);
+    /// ```
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Calculates the great-circle distance to another location using the haversine formula.
+    ///
+    /// Returns the distance in kilometers. This calculation assumes a spherical Earth
+    /// with radius 6371 km, which provides accuracy within 0.5% for most distances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let nyc = Location::new(40.7128, -74.0060);
+    /// let la = Location::new(34.0522, -118.2437);
+    ///
+    /// let distance = nyc.distance_to(&la);
+    /// assert!(distance > 3900.0 && distance < 4000.0); // ~3944 km
+    /// ```
+    pub fn distance_to(&self, other: &Location) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lat = (other.latitude - self.latitude).to_radians();
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+
+        6371.0 * c
+    }
+
+    /// Calculates the great-circle distance to another location in single precision.
+    ///
+    /// Uses the same haversine formula as [`Location::distance_to()`], but converts the
+    /// coordinates to `f32` and computes in `f32` throughout. Coordinates then carry
+    /// about a meter of rounding error, which is negligible at city scale and lets
+    /// batch code pack twice as many values per SIMD register. Prefer
+    /// [`Location::distance_to()`] unless that density matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let nyc = Location::new(40.7128, -74.0060);
+    /// let la = Location::new(34.0522, -118.2437);
+    ///
+    /// let distance = nyc.distance_to_f32(&la);
+    /// assert!((f64::from(distance) - nyc.distance_to(&la)).abs() < 0.01);
+    /// ```
+    pub fn distance_to_f32(&self, other: &Location) -> f32 {
+        let (lat1, lon1) = (self.latitude as f32, self.longitude as f32);
+        let (lat2, lon2) = (other.latitude as f32, other.longitude as f32);
+        let delta_lat = (lat2 - lat1).to_radians();
+        let delta_lon = (lon2 - lon1).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.to_radians().cos() * lat2.to_radians().cos() * (delta_lon / 2.0).sin().powi(2);
+        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+
+        6371.0 * c
+    }
+
+    /// Calculates the distance to another location on the WGS84 ellipsoid using
+    /// Vincenty's inverse formula.
+    ///
+    /// Returns the distance in **meters** (unlike [`Location::distance_to`], which returns
+    /// kilometers). The result is accurate to within a millimeter on the WGS84 ellipsoid.
+    ///
+    /// # Accuracy vs. Performance
+    ///
+    /// | Method | Model | Error | Cost |
+    /// |--------|-------|-------|------|
+    /// | [`distance_to`](Location::distance_to) | Sphere (6371 km) | up to ~0.5% | a handful of trig calls |
+    /// | `distance_to_ellipsoidal` | WGS84 ellipsoid | < 1 mm | iterative, typically 3-10 iterations |
+    ///
+    /// Use the spherical version for ranking and rough distances, and this one for
+    /// surveying, aviation, or anything that needs sub-meter accuracy.
+    ///
+    /// # Near-Antipodal Points
+    ///
+    /// Vincenty's iteration does not converge for some nearly antipodal point pairs. In
+    /// that case this method falls back to the spherical result (converted to meters).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// // Flinders Peak to Buninyong, the classic Vincenty test case
+    /// let flinders = Location::new(-37.951033417, 144.424867889);
+    /// let buninyong = Location::new(-37.652821139, 143.926495528);
+    ///
+    /// let meters = flinders.distance_to_ellipsoidal(&buninyong);
+    /// assert!((meters - 54_972.271).abs() < 0.01);
+    ///
+    /// // Nearly antipodal: falls back to the spherical distance instead of diverging
+    /// let a = Location::new(0.0, 0.0);
+    /// let b = Location::new(0.5, 179.7);
+    /// assert!(a.distance_to_ellipsoidal(&b).is_finite());
+    /// ```
+    pub fn distance_to_ellipsoidal(&self, other: &Location) -> f64 {
+        const A: f64 = 6_378_137.0;
+        const F: f64 = 1.0 / 298.257_223_563;
+        const B: f64 = A * (1.0 - F);
+
+        let l = (other.longitude - self.longitude).to_radians();
+        let u1 = ((1.0 - F) * self.latitude.to_radians().tan()).atan();
+        let u2 = ((1.0 - F) * other.latitude.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                return 0.0;
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            let cos_2sigma_m = if cos_sq_alpha != 0.0 {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            } else {
+                0.0
+            };
+            let c = F / 16.0 * cos_sq_alpha * (4.0 + F * (4.0 - 3.0 * cos_sq_alpha));
+
+            let previous = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+            if (lambda - previous).abs() < 1e-12 {
+                let u_sq = cos_sq_alpha * (A * A - B * B) / (B * B);
+                let big_a = 1.0
+                    + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+                let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + big_b / 4.0
+                            * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                                - big_b / 6.0
+                                    * cos_2sigma_m
+                                    * (-3.0 + 4.0 * sin_sigma.powi(2))
+                                    * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+                return B * big_a * (sigma - delta_sigma);
+            }
+        }
+
+        self.distance_to(other) * 1000.0
+    }
+
+    /// Approximates the distance to another location using the equirectangular projection.
+    ///
+    /// Returns the distance in kilometers. The approximation projects both points onto a
+    /// plane scaled by the cosine of their mean latitude, which needs a single
+    /// trigonometric call instead of the several used by [`Location::distance_to`].
+    ///
+    /// At city scale (a few tens of kilometers) the error is well under a meter, so
+    /// nearest-neighbor comparisons inside the lookup grid rank candidates identically to
+    /// haversine. The error grows with distance and towards the poles; prefer
+    /// [`Location::distance_to`] for anything beyond regional distances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let a = Location::new(48.8566, 2.3522);
+    /// let b = Location::new(48.8738, 2.2950);
+    ///
+    /// let exact = a.distance_to(&b);
+    /// let fast = a.equirectangular_distance_to(&b);
+    /// assert!((exact - fast).abs() < 0.001);
+    /// ```
+    pub fn equirectangular_distance_to(&self, other: &Location) -> f64 {
+        let mean_lat = ((self.latitude + other.latitude) / 2.0).to_radians();
+        let mut delta_lon = other.longitude - self.longitude;
+        if delta_lon > 180.0 {
+            delta_lon -= 360.0;
+        } else if delta_lon < -180.0 {
+            delta_lon += 360.0;
+        }
+
+        let x = delta_lon.to_radians() * mean_lat.cos();
+        let y = (other.latitude - self.latitude).to_radians();
+        6371.0 * (x * x + y * y).sqrt()
+    }
+
+    /// Returns the point at `fraction` of the way along the great circle to `other`.
+    ///
+    /// Interpolates spherically (slerp over the unit sphere), so equal steps in
+    /// `fraction` are equal steps in [`distance_to`](Location::distance_to): `0.0` is
+    /// `self`, `1.0` is `other` and `0.5` is the great-circle midpoint. Fractions outside
+    /// 0 to 1 extrapolate along the same great circle.
+    ///
+    /// # Antipodal Points
+    ///
+    /// Every great circle joins two antipodal points, so the path is undefined. In that
+    /// case the path follows the meridian through `self`, heading north first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let nyc = Location::new(40.7128, -74.0060);
+    /// let la = Location::new(34.0522, -118.2437);
+    ///
+    /// let quarter = nyc.interpolate(&la, 0.25);
+    /// let total = nyc.distance_to(&la);
+    /// assert!((nyc.distance_to(&quarter) - total * 0.25).abs() < 1e-6);
+    /// assert!((quarter.distance_to(&la) - total * 0.75).abs() < 1e-6);
+    ///
+    /// let antipode = Location::new(0.0, 0.0).interpolate(&Location::new(0.0, 180.0), 0.5);
+    /// assert!((antipode.latitude - 90.0).abs() < 1e-9);
+    /// ```
+    pub fn interpolate(&self, other: &Location, fraction: f64) -> Location {
+        let to_vector = |loc: &Location| {
+            let (lat, lon) = (loc.latitude.to_radians(), loc.longitude.to_radians());
+            [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+        };
+        let a = to_vector(self);
+        let b = to_vector(other);
+
+        let cross = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        let sin_angle = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+        let cos_angle = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+
+        let point = if sin_angle > 1e-12 {
+            let angle = sin_angle.atan2(cos_angle);
+            let wa = ((1.0 - fraction) * angle).sin() / sin_angle;
+            let wb = (fraction * angle).sin() / sin_angle;
+            [
+                wa * a[0] + wb * b[0],
+                wa * a[1] + wb * b[1],
+                wa * a[2] + wb * b[2],
+            ]
+        } else if cos_angle > 0.0 {
+            return *self;
+        } else {
+            // Unit vector pointing north along the meridian through `self`
+            let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+            let north = [-lat.sin() * lon.cos(), -lat.sin() * lon.sin(), lat.cos()];
+            let (sin, cos) = (fraction * std::f64::consts::PI).sin_cos();
+            [
+                cos * a[0] + sin * north[0],
+                cos * a[1] + sin * north[1],
+                cos * a[2] + sin * north[2],
+            ]
+        };
+
+        Location::new(
+            point[2].atan2(point[0].hypot(point[1])).to_degrees(),
+            point[1].atan2(point[0]).to_degrees(),
+        )
+    }
+
+    /// Returns the point diametrically opposite on the globe.
+    ///
+    /// The latitude is negated and the longitude shifted by 180°, staying within -180 to
+    /// 180. The antipode is the farthest point from `self`, half the Earth's
+    /// circumference away, which makes it a handy input for testing the antipodal edge
+    /// cases of [`interpolate`](Location::interpolate) and
+    /// [`centroid`](Location::centroid).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let madrid = Location::new(40.4168, -3.7038);
+    /// let antipode = madrid.antipode();
+    /// assert_eq!(antipode.latitude, -40.4168);
+    /// assert!((antipode.longitude - 176.2962).abs() < 1e-9);
+    /// assert!((madrid.distance_to(&antipode) - 6371.0 * std::f64::consts::PI).abs() < 1e-6);
+    /// ```
+    pub fn antipode(&self) -> Location {
+        let longitude = if self.longitude > 0.0 {
+            self.longitude - 180.0
+        } else {
+            self.longitude + 180.0
+        };
+        Location::new(-self.latitude, longitude)
+    }
+
+    /// Returns the geographic centroid of a set of locations.
+    ///
+    /// Each location is converted to a unit vector on the sphere; the vectors are summed
+    /// and the sum is projected back to latitude and longitude. Unlike averaging raw
+    /// degrees, this handles sets spanning the antimeridian (the centroid of points at
+    /// 179° and -179° lies at 180°, not 0°). Returns `None` for an empty slice, and also
+    /// when the vectors cancel out and no center exists, such as for two antipodal
+    /// points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let fiji = [Location::new(-18.0, 179.0), Location::new(-18.0, -179.0)];
+    /// let center = Location::centroid(&fiji).unwrap();
+    /// assert!((center.longitude.abs() - 180.0).abs() < 1e-9);
+    /// assert!((center.latitude + 18.0).abs() < 0.01);
+    ///
+    /// assert!(Location::centroid(&[]).is_none());
+    /// ```
+    pub fn centroid(locations: &[Location]) -> Option<Location> {
+        let mut sum = [0.0f64; 3];
+        for location in locations {
+            let (lat, lon) = (
+                location.latitude.to_radians(),
+                location.longitude.to_radians(),
+            );
+            sum[0] += lat.cos() * lon.cos();
+            sum[1] += lat.cos() * lon.sin();
+            sum[2] += lat.sin();
+        }
+
+        let horizontal = sum[0].hypot(sum[1]);
+        if locations.is_empty() || horizontal.hypot(sum[2]) < 1e-9 * locations.len() as f64 {
+            return None;
+        }
+        Some(Location::new(
+            sum[2].atan2(horizontal).to_degrees(),
+            sum[1].atan2(sum[0]).to_degrees(),
+        ))
+    }
+
+    /// Snaps the location to the center of its cell in a grid of `cell_degrees` squares.
+    ///
+    /// Each coordinate is floor-divided by `cell_degrees`, so every point in a cell maps
+    /// to the same center and cells tile the globe evenly on both sides of the equator
+    /// and prime meridian. This coarsens coordinates deterministically before they are
+    /// logged or looked up: `0.1` matches the ~11 km cells of the lookup grid, `0.01`
+    /// gives ~1.1 km. Centers are clamped to the valid coordinate range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_degrees` is not a positive finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let coarse = Location::new(48.8566, 2.3522).quantize(0.1);
+    /// assert!((coarse.latitude - 48.85).abs() < 1e-9);
+    /// assert!((coarse.longitude - 2.35).abs() < 1e-9);
+    ///
+    /// let south = Location::new(-33.8688, 151.2093).quantize(1.0);
+    /// assert_eq!((south.latitude, south.longitude), (-33.5, 151.5));
+    /// ```
+    pub fn quantize(&self, cell_degrees: f64) -> Location {
+        assert!(
+            cell_degrees.is_finite() && cell_degrees > 0.0,
+            "cell size must be a positive number of degrees, got {}",
+            cell_degrees
+        );
+        let center = |value: f64, max: f64| {
+            (((value / cell_degrees).floor() + 0.5) * cell_degrees).clamp(-max, max)
+        };
+        Location::new(center(self.latitude, 90.0), center(self.longitude, 180.0))
+    }
+
+    /// Encodes the location as a geohash string of the given length.
+    ///
+    /// Each additional character narrows the cell by 5 bits. Precision 5 yields cells of
+    /// roughly 4.9 km × 4.9 km (±2.4 km), precision 6 roughly 1.2 km × 0.6 km, which matches
+    /// the resolution of the place database. Precision is capped at 12 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let loc = Location::new(57.64911, 10.40744);
+    /// assert_eq!(loc.geohash(11), "u4pruydqqvj");
+    /// assert_eq!(loc.geohash(5), "u4pru");
+    /// ```
+    pub fn geohash(&self, precision: usize) -> String {
+        let precision = precision.min(12);
+        let (mut lat_range, mut lon_range) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut hash = String::with_capacity(precision);
+        let mut even = true;
+        let (mut bits, mut ch) = (0, 0usize);
+
+        while hash.len() < precision {
+            let (range, value) = if even {
+                (&mut lon_range, self.longitude)
+            } else {
+                (&mut lat_range, self.latitude)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            ch <<= 1;
+            if value >= mid {
+                ch |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+
+            bits += 1;
+            if bits == 5 {
+                hash.push(GEOHASH_ALPHABET[ch] as char);
+                bits = 0;
+                ch = 0;
+            }
+        }
+        hash
+    }
+
+    /// Decodes a geohash string into the center of the cell it describes.
+    ///
+    /// Decoding is case-insensitive. Returns `None` for an empty string or if it contains
+    /// characters outside the geohash base32 alphabet (`a`, `i`, `l`, and `o` are excluded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let loc = Location::from_geohash("u4pruydqqvj").unwrap();
+    /// assert!((loc.latitude - 57.64911).abs() < 0.0001);
+    /// assert!((loc.longitude - 10.40744).abs() < 0.0001);
+    ///
+    /// assert!(Location::from_geohash("invalid").is_none());
+    /// ```
+    pub fn from_geohash(s: &str) -> Option<Location> {
+        if s.is_empty() {
+            return None;
+        }
+
+        let (mut lat_range, mut lon_range) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut even = true;
+
+        for c in s.bytes() {
+            let value = GEOHASH_ALPHABET
+                .iter()
+                .position(|&b| b == c.to_ascii_lowercase())?;
+            for shift in (0..5).rev() {
+                let range: &mut (f64, f64) = if even { &mut lon_range } else { &mut lat_range };
+                let mid = (range.0 + range.1) / 2.0;
+                if (value >> shift) & 1 == 1 {
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+                even = !even;
+            }
+        }
+
+        Some(Location::new(
+            (lat_range.0 + lat_range.1) / 2.0,
+            (lon_range.0 + lon_range.1) / 2.0,
+        ))
+    }
+
+    /// Encodes the location as a Morton (Z-order) key.
+    ///
+    /// Latitude and longitude are quantized to the crate's fixed-point precision of
+    /// 1e-5 degrees (about 1.1 m), shifted to be non-negative, and their bits are
+    /// interleaved with longitude in the even and latitude in the odd positions. Sorting
+    /// by the key keeps nearby coordinates close together, so key ranges make good
+    /// shards for batch processing. Coordinates outside the valid range are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let paris = Location::new(48.8566, 2.3522).morton_key();
+    /// let versailles = Location::new(48.8049, 2.1204).morton_key();
+    /// let tokyo = Location::new(35.6762, 139.6503).morton_key();
+    /// assert!(paris.abs_diff(versailles) < paris.abs_diff(tokyo));
+    ///
+    /// let decoded = Location::from_morton_key(paris);
+    /// assert!((decoded.latitude - 48.8566).abs() < 1e-9);
+    /// assert!((decoded.longitude - 2.3522).abs() < 1e-9);
+    /// ```
+    pub fn morton_key(&self) -> u64 {
+        let quantize = |value: f64, max: f64| ((value.clamp(-max, max) + max) * 100000.0).round();
+        let lat = quantize(self.latitude, 90.0) as u32;
+        let lon = quantize(self.longitude, 180.0) as u32;
+        spread_bits(lon) | (spread_bits(lat) << 1)
+    }
+
+    /// Decodes a key produced by [`Location::morton_key()`].
+    ///
+    /// The result matches the original location to within the 1e-5 degree quantization.
+    pub fn from_morton_key(key: u64) -> Location {
+        let lat = f64::from(compact_bits(key >> 1)) / 100000.0 - 90.0;
+        let lon = f64::from(compact_bits(key)) / 100000.0 - 180.0;
+        Location::new(lat, lon)
+    }
+
+    /// Encodes the location as a Military Grid Reference System (MGRS) string.
+    ///
+    /// `precision` is the number of digits per axis, from 0 (100 km square) to 5 (1 m);
+    /// larger values are capped at 5. Digits are truncated, as MGRS requires, so the
+    /// reference names the square containing the location. The reference is written
+    /// without spaces (e.g., `"18SUJ2339007393"`).
+    ///
+    /// Returns `None` in the polar regions (south of 80°S and north of 84°N), which MGRS
+    /// covers with the UPS grid instead of UTM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let loc = Location::new(38.897676, -77.036548);
+    /// assert_eq!(loc.to_mgrs(5).unwrap(), "18SUJ2339007393");
+    /// assert_eq!(loc.to_mgrs(2).unwrap(), "18SUJ2307");
+    /// assert!(Location::new(89.0, 0.0).to_mgrs(5).is_none());
+    /// ```
+    pub fn to_mgrs(&self, precision: u8) -> Option<String> {
+        crate::utm::to_mgrs(self.latitude, self.longitude, precision)
+    }
+
+    /// Decodes an MGRS reference into the south-west corner of the square it describes.
+    ///
+    /// Parsing is case-insensitive and ignores whitespace, so both `"18SUJ2339007393"`
+    /// and `"18S UJ 23390 07393"` are accepted. Returns `None` for malformed references,
+    /// an odd number of digits, or UPS (polar) references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let loc = Location::from_mgrs("18S UJ 23390 07393").unwrap();
+    /// assert!((loc.latitude - 38.897676).abs() < 0.00001);
+    /// assert!((loc.longitude - -77.036548).abs() < 0.00001);
+    ///
+    /// assert!(Location::from_mgrs("18SUJ123").is_none());
+    /// ```
+    pub fn from_mgrs(s: &str) -> Option<Location> {
+        let (latitude, longitude) = crate::utm::from_mgrs(s)?;
+        Some(Location::new(latitude, longitude))
+    }
+
+    /// Projects the location to Universal Transverse Mercator coordinates on WGS84.
+    ///
+    /// The zone follows the standard 6° grid, including the wider zones used for
+    /// southwestern Norway (32V) and Svalbard (31X-37X). Returns `None` south of 80°S and
+    /// north of 84°N, where UTM is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::{Hemisphere, Location};
+    ///
+    /// let utm = Location::new(38.897676, -77.036548).to_utm().unwrap();
+    /// assert_eq!(utm.zone, 18);
+    /// assert_eq!(utm.hemisphere, Hemisphere::North);
+    /// assert_eq!(utm.easting.round(), 323390.0);
+    /// assert_eq!(utm.northing.round(), 4307393.0);
+    ///
+    /// // Bergen lies in the widened zone 32
+    /// assert_eq!(Location::new(60.39, 5.32).to_utm().unwrap().zone, 32);
+    /// ```
+    pub fn to_utm(&self) -> Option<Utm> {
+        let zone = crate::utm::zone(self.latitude, self.longitude)?;
+        let (easting, northing) = crate::utm::project(self.latitude, self.longitude, zone);
+        Some(Utm {
+            zone,
+            hemisphere: if self.latitude < 0.0 {
+                Hemisphere::South
+            } else {
+                Hemisphere::North
+            },
+            easting,
+            northing,
+        })
+    }
+
+    /// Converts UTM coordinates on WGS84 back to a location.
+    ///
+    /// Accepts coordinates outside the nominal extent of their zone, as produced by the
+    /// widened Norway and Svalbard zones, at slightly reduced accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::{Hemisphere, Location, Utm};
+    ///
+    /// let utm = Utm {
+    ///     zone: 56,
+    ///     hemisphere: Hemisphere::South,
+    ///     easting: 334368.6,
+    ///     northing: 6250948.3,
+    /// };
+    /// let loc = Location::from_utm(&utm);
+    /// assert!((loc.latitude - -33.8688).abs() < 0.00001);
+    /// assert!((loc.longitude - 151.2093).abs() < 0.00001);
+    /// ```
+    pub fn from_utm(utm: &Utm) -> Location {
+        let (latitude, longitude) = crate::utm::unproject(
+            utm.zone,
+            utm.hemisphere == Hemisphere::North,
+            utm.easting,
+            utm.northing,
+        );
+        Location::new(latitude, longitude)
+    }
+
+    /// Parses a coordinate pair written in degrees, minutes and seconds.
+    ///
+    /// Accepts the common notations: hemisphere letters before or after each coordinate
+    /// (`48°51'24"N 2°21'8"E`, `N 48° 51.4' E 2° 21.13'`) or signed values
+    /// (`-33°52'8", 151°12'33"`), with degree/minute/second marks as Unicode symbols
+    /// (`°`, `′`, `″`) or ASCII substitutes (`'`, `"`, `''`). Components without marks
+    /// are read as degrees, minutes, seconds in order, so `48 51 24 N 2 21 8 E` and
+    /// decimal degrees (`48.8566, 2.3522`) work too. With hemisphere letters, either
+    /// coordinate may come first.
+    ///
+    /// Returns `None` if the input is not two coordinates, minutes or seconds are 60 or
+    /// more, or the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let paris = Location::from_dms("48°51'24\"N 2°21'8\"E").unwrap();
+    /// assert!((paris.latitude - 48.856_667).abs() < 1e-6);
+    /// assert!((paris.longitude - 2.352_222).abs() < 1e-6);
+    ///
+    /// let sydney = Location::from_dms("S 33° 52.13′ E 151° 12.55′").unwrap();
+    /// assert!((sydney.latitude + 33.868_833).abs() < 1e-6);
+    ///
+    /// let swapped = Location::from_dms("2 21 8 E, 48 51 24 N").unwrap();
+    /// assert_eq!(swapped.latitude, paris.latitude);
+    ///
+    /// assert!(Location::from_dms("48°75'N 2°E").is_none());
+    /// ```
+    pub fn from_dms(s: &str) -> Option<Location> {
+        let (latitude, longitude) = crate::dms::parse(s)?;
+        Some(Location::new(latitude, longitude))
+    }
+
+    /// Formats the location in degrees, minutes and seconds with hemisphere letters.
+    ///
+    /// Seconds are given to a tenth (about 3 m), e.g. `48°51'23.8"N 2°21'7.9"E`. The
+    /// output parses back with [`Location::from_dms()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let paris = Location::new(48.8566, 2.3522);
+    /// assert_eq!(paris.to_dms_string(), "48°51'23.8\"N 2°21'7.9\"E");
+    ///
+    /// let rio = Location::new(-22.9068, -43.1729);
+    /// assert_eq!(rio.to_dms_string(), "22°54'24.5\"S 43°10'22.4\"W");
+    /// ```
+    pub fn to_dms_string(&self) -> String {
+        crate::dms::format(self.latitude, self.longitude)
+    }
+
+    /// Returns the index of the H3 cell containing the location at `resolution` (0-15).
+    ///
+    /// Requires the `h3` feature. Returns `None` for an invalid resolution or
+    /// non-finite coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::Location;
+    ///
+    /// let paris = Location::new(48.8566, 2.3522);
+    /// assert_eq!(paris.to_h3(9), Some(0x891f_b466_257f_fff));
+    /// assert_eq!(paris.to_h3(16), None);
+    /// ```
+    #[cfg(feature = "h3")]
+    pub fn to_h3(&self, resolution: u8) -> Option<u64> {
+        let resolution = h3o::Resolution::try_from(resolution).ok()?;
+        let latlng = h3o::LatLng::new(self.latitude, self.longitude).ok()?;
+        Some(u64::from(latlng.to_cell(resolution)))
+    }
+}
+
+/// Converts a `(latitude, longitude)` tuple into a [`Location`].
+///
+/// Note the order: GeoJSON and many mapping libraries use `(longitude, latitude)`, so
+/// swap the components when converting from those sources.
+///
+/// # Examples
+///
+/// ```
+/// use genom::Location;
+///
+/// let loc = Location::from((40.7128, -74.0060));
+/// assert_eq!(loc.latitude, 40.7128);
+/// ```
+impl From<(f64, f64)> for Location {
+    fn from((latitude, longitude): (f64, f64)) -> Self {
+        Self::new(latitude, longitude)
+    }
+}
+
+/// Converts a `[latitude, longitude]` array into a [`Location`].
+///
+/// Note the order: GeoJSON positions are `[longitude, latitude]`, so swap the
+/// components when converting from GeoJSON.
+///
+/// # Examples
+///
+/// ```
+/// use genom::Location;
+///
+/// let loc = Location::from([40.7128, -74.0060]);
+/// assert_eq!(loc.longitude, -74.0060);
+/// ```
+impl From<[f64; 2]> for Location {
+    fn from([latitude, longitude]: [f64; 2]) -> Self {
+        Self::new(latitude, longitude)
+    }
+}
+
+/// Takes the coordinates of a [`Place`], same as [`Place::location()`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() {
+/// use genom::Location;
+///
+/// let place = genom::lookup(40.7128, -74.0060).unwrap();
+/// let loc = Location::from(&place);
+/// assert_eq!(loc.latitude, place.latitude);
+/// # }
+/// ```
+impl From<&Place> for Location {
+    fn from(place: &Place) -> Self {
+        place.location()
+    }
+}
+
+/// Parses a `"lat,lon"` string such as `"40.7128,-74.0060"`.
+///
+/// Components may be separated by a comma, whitespace, or both (`"40.7128, -74.0060"`,
+/// `"40.7128 -74.0060"`). Surrounding whitespace is ignored. Latitude must lie within
+/// -90 to 90 and longitude within -180 to 180.
+///
+/// # Examples
+///
+/// ```
+/// use genom::Location;
+///
+/// let loc: Location = " 40.7128, -74.0060 ".parse().unwrap();
+/// assert_eq!(loc.latitude, 40.7128);
+/// assert_eq!(loc.longitude, -74.0060);
+///
+/// assert!("40.7128".parse::<Location>().is_err());
+/// assert!("40.7128,-74.0060,10".parse::<Location>().is_err());
+/// assert!("40.7128,-190.0".parse::<Location>().is_err());
+/// ```
+impl FromStr for Location {
+    type Err = ParseLocationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|p| !p.is_empty())
+            .collect();
+        if parts.len() != 2 || s.matches(',').count() > 1 {
+            return Err(ParseLocationError::WrongComponentCount(
+                parts.len().max(s.matches(',').count() + 1),
+            ));
+        }
+
+        let parse = |p: &str| {
+            p.parse::<f64>()
+                .map_err(|_| ParseLocationError::InvalidNumber(p.to_string()))
+        };
+        let (latitude, longitude) = (parse(parts[0])?, parse(parts[1])?);
+
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(ParseLocationError::LatitudeOutOfRange(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(ParseLocationError::LongitudeOutOfRange(longitude));
+        }
+        Ok(Location::new(latitude, longitude))
+    }
+}
+
+fn is_national_capital(feature_code: &str) -> bool {
+    matches!(feature_code, "PPLC" | "PPLG")
+}
+
+fn is_regional_capital(feature_code: &str) -> bool {
+    matches!(feature_code, "PPLA" | "PPLA2")
+}
+
+/// Hemisphere of a UTM coordinate, which determines whether the false northing applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Hemisphere {
+    /// Northern hemisphere (northing measured from the equator)
+    North,
+    /// Southern hemisphere (northing includes a 10,000 km false northing)
+    South,
+}
+
+/// A Universal Transverse Mercator coordinate on the WGS84 ellipsoid.
+///
+/// Returned by [`Location::to_utm()`] and converted back with [`Location::from_utm()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Utm {
+    /// Zone number (1-60)
+    pub zone: u8,
+    /// Hemisphere the northing is relative to
+    pub hemisphere: Hemisphere,
+    /// Easting in meters, including the 500 km false easting
+    pub easting: f64,
+    /// Northing in meters
+    pub northing: f64,
 }
 
-impl Location {
-    /// Constructs a new Location from coordinates.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use genom::Location;
-    ///
-    /// let loc = Location::new(40.7128, -74.0060);
-    /// assert_eq!(loc.latitude, 40.7128);
-    /// assert_eq!(loc.longitude, -74.0060);
-    /// ```
-    pub fn new(latitude: f64, longitude: f64) -> Self {
-        Self {
-            latitude,
-            longitude,
-        }
+/// A distance model used to rank candidate places during a lookup.
+///
+/// [`Geocoder`](crate::Geocoder) compares candidates with [`Equirectangular`] by
+/// default; [`Geocoder::with_metric()`](crate::Geocoder::with_metric) swaps in another
+/// model. Implementations should return kilometers so that
+/// [`LookupDebug::nearest_distance_km`] stays meaningful, though only the ordering of
+/// the returned values affects which place is chosen.
+///
+/// Lookups that search beyond the 3×3 neighborhood, such as
+/// [`Geocoder::lookup_with_options()`](crate::Geocoder::lookup_with_options), stop
+/// early once no unsearched cell can hold a closer place. That bound is in great-circle
+/// kilometers and only holds for [`Haversine`], [`Ellipsoidal`] and [`Equirectangular`],
+/// so with any other metric these lookups search every ring up to their limit.
+///
+/// # Examples
+///
+/// ```
+/// use genom::types::{CompactPlace, Database, Grid};
+/// use genom::{DistanceMetric, Geocoder, Location, LookupOptions};
+///
+/// /// Taxicab distance in degrees, for grid-world simulations.
+/// struct Manhattan;
+///
+/// impl DistanceMetric for Manhattan {
+///     fn distance(&self, a: &Location, b: &Location) -> f64 {
+///         (a.latitude - b.latitude).abs() + (a.longitude - b.longitude).abs()
+///     }
+/// }
+///
+/// let d = Manhattan.distance(&Location::new(0.0, 0.0), &Location::new(1.0, 2.0));
+/// assert_eq!(d, 3.0);
+///
+/// let mut db = Database::default();
+/// db.strings = ["", "Diagonal", "Straight", "XX", "UTC", "PPL"].map(String::from).to_vec();
+/// let mut cells = Vec::new();
+/// for (city, latitude, longitude) in [(1, 0.25, 0.25), (2, 0.35, 0.05)] {
+///     let (lat, lon) = ((latitude * 100000.0) as i32, (longitude * 100000.0) as i32);
+///     let place = CompactPlace {
+///         city, city_ascii: city, region: 0, region_code: 0, district: 0,
+///         subdistrict: 0, country_code: 3, postal_code: 0, timezone: 4,
+///         feature_code: 5, modified: 0, population: 0, lat, lon,
+///     };
+///     db.push(place);
+///     let key = ((lat / 10000) as i16, (lon / 10000) as i16);
+///     cells.push((key, vec![db.len() as u32 - 1]));
+/// }
+/// db.grid = Grid::from_cells(cells);
+/// let geocoder = Geocoder::from_database(db).with_metric(Manhattan);
+///
+/// // "Diagonal" (ring 2) is 0.4 away, "Straight" (ring 3) only 0.3
+/// let options = LookupOptions::default().with_max_rings(10);
+/// let place = geocoder.lookup_with_options(0.05, 0.05, &options).unwrap();
+/// assert_eq!(place.city, "Straight");
+/// ```
+pub trait DistanceMetric: Send + Sync {
+    /// Returns the distance between `a` and `b`.
+    fn distance(&self, a: &Location, b: &Location) -> f64;
+}
+
+/// Great-circle distance in kilometers, see [`Location::distance_to()`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Haversine;
+
+impl DistanceMetric for Haversine {
+    fn distance(&self, a: &Location, b: &Location) -> f64 {
+        a.distance_to(b)
     }
+}
 
-    /// Calculates the great-circle distance to another location using the haversine formula.
-    ///
-    /// Returns the distance in kilometers. This calculation assumes a spherical Earth
-    /// with radius 6371 km, which provides accuracy within 0.5% for most distances.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use genom::Location;
-    ///
-    /// let nyc = Location::new(40.7128, -74.0060);
-    /// let la = Location::new(34.0522, -118.2437);
-    ///
-    /// let distance = nyc.distance_to(&la);
-    /// assert!(distance > 3900.0 && distance < 4000.0); // ~3944 km
-    /// ```
-    pub fn distance_to(&self, other: &Location) -> f64 {
-        let lat1 = self.latitude.to_radians();
-        let lat2 = other.latitude.to_radians();
-        let delta_lat = (other.latitude - self.latitude).to_radians();
-        let delta_lon = (other.longitude - self.longitude).to_radians();
+/// Distance on the WGS84 ellipsoid in kilometers, see
+/// [`Location::distance_to_ellipsoidal()`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ellipsoidal;
 
-        let a = (delta_lat / 2.0).sin().powi(2)
-            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
-        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+impl DistanceMetric for Ellipsoidal {
+    fn distance(&self, a: &Location, b: &Location) -> f64 {
+        a.distance_to_ellipsoidal(b) / 1000.0
+    }
+}
 
-        6371.0 * c
+/// Equirectangular approximation in kilometers, see
+/// [`Location::equirectangular_distance_to()`]. The default metric of
+/// [`Geocoder`](crate::Geocoder): it ranks nearby candidates like [`Haversine`] at a
+/// fraction of the cost.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Equirectangular;
+
+impl DistanceMetric for Equirectangular {
+    fn distance(&self, a: &Location, b: &Location) -> f64 {
+        a.equirectangular_distance_to(b)
     }
 }
 
+/// Spreads the bits of `value` into the even bit positions of a `u64`.
+fn spread_bits(value: u32) -> u64 {
+    let mut x = u64::from(value);
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Inverse of [`spread_bits`]: gathers the even bit positions of `value`.
+fn compact_bits(value: u64) -> u32 {
+    let mut x = value & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x >> 4)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x >> 8)) & 0x0000_FFFF_0000_FFFF;
+    ((x | (x >> 16)) & 0x0000_0000_FFFF_FFFF) as u32
+}
+
+/// Base32 alphabet used by geohash encoding.
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
 /// Compressed storage format using string table indices and fixed-point coordinates.
 ///
 /// This is the internal storage representation used in the database. All string fields
@@ -137,23 +1933,36 @@ impl Location {
 /// are stored as `i32` fixed-point values (multiplied by 100,000).
 ///
 /// This reduces memory footprint by approximately 70% compared to storing full
-/// [`Place`] structs.
-#[derive(Serialize, Deserialize, bincode::Encode, bincode::Decode)]
+/// [`Place`] structs. Inside a [`Database`] the fields are stored column-wise; this
+/// struct is the row view returned by [`Database::place()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct CompactPlace {
     /// Index into the string table for the city name
     pub city: u32,
+    /// Index into the string table for the ASCII city name
+    pub city_ascii: u32,
     /// Index into the string table for the region name
     pub region: u32,
     /// Index into the string table for the region code
     pub region_code: u32,
     /// Index into the string table for the district name
     pub district: u32,
+    /// Index into the string table for the subdistrict name
+    pub subdistrict: u32,
     /// Index into the string table for the country code
     pub country_code: u32,
     /// Index into the string table for the postal code
     pub postal_code: u32,
     /// Index into the string table for the timezone identifier
     pub timezone: u32,
+    /// Index into the string table for the GeoNames feature code
+    pub feature_code: u32,
+    /// Date of the last GeoNames modification in days since 1970-01-01 (0 if unknown)
+    pub modified: u16,
+    /// Number of inhabitants (0 if unknown)
+    pub population: u32,
     /// Latitude as fixed-point integer (multiply by 100,000 to get decimal degrees)
     pub lat: i32,
     /// Longitude as fixed-point integer (multiply by 100,000 to get decimal degrees)
@@ -170,6 +1979,30 @@ impl CompactPlace {
             longitude: self.lon as f64 / 100000.0,
         }
     }
+
+    /// Converts the stored modification day count to a date.
+    ///
+    /// Returns `None` if the modification date is unknown.
+    pub fn modified_date(&self) -> Option<NaiveDate> {
+        date_from_days(self.modified)
+    }
+}
+
+/// The bincode configuration of [`Place::encode()`], pinned so stored blobs stay readable.
+#[cfg(feature = "bincode")]
+fn place_bincode_config() -> impl bincode::config::Config {
+    bincode::config::standard()
+        .with_little_endian()
+        .with_variable_int_encoding()
+        .with_no_limit()
+}
+
+/// Converts a day count since 1970-01-01 to a date, treating 0 as unknown.
+fn date_from_days(days: u16) -> Option<NaiveDate> {
+    match days {
+        0 => None,
+        days => DateTime::from_timestamp(i64::from(days) * 86_400, 0).map(|t| t.date_naive()),
+    }
 }
 
 /// The complete spatial database structure with string interning and grid index.
@@ -178,30 +2011,761 @@ impl CompactPlace {
 /// string interning to deduplicate common strings and a spatial grid index for
 /// fast coordinate lookups.
 ///
+/// # Storage Layout
+///
+/// Places are stored column-wise (struct-of-arrays): the `i`-th place consists of the
+/// `i`-th element of every column. The nearest-neighbor scan only reads the `lats` and
+/// `lons` columns, so it touches contiguous coordinate memory instead of loading full
+/// records. Use [`Database::place()`] to reassemble a single row as a [`CompactPlace`].
+///
+/// All columns have the same length.
+///
 /// # Spatial Indexing Strategy
 ///
 /// The grid divides the world into 0.1° × 0.1° cells. For a lookup:
 ///
 /// 1. Quantize the input coordinates to a grid key: `(lat * 100000 / 10000, lon * 100000 / 10000)`
 /// 2. Search the target cell and 8 neighboring cells (3×3 grid)
-/// 3. Calculate haversine distance to all candidates in these cells
+/// 3. Calculate equirectangular distance to all candidates in these cells
 /// 4. Return the nearest place
 ///
-/// This provides O(1) average-case lookup with a small constant factor (typically 10-50 candidates to check).
-#[derive(Serialize, Deserialize, bincode::Encode, bincode::Decode)]
+/// Locating a cell is a binary search over the sorted cell keys (O(log cells)), and only a small
+/// number of candidates need checking (typically 10-50).
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct Database {
-    /// Deduplicated string table. All string fields in [`CompactPlace`]
-    /// are stored as indices into this vector. Common strings like country codes and
-    /// timezone names are stored only once.
+    /// Build time from the file header as Unix seconds (0 if unknown)
+    pub built_at: u64,
+    /// Most recent GeoNames modification date of any place, in days since 1970-01-01
+    /// (0 if unknown)
+    pub data_date: u16,
+    /// Deduplicated string table. All string columns are stored as indices into this
+    /// vector. Common strings like country codes and timezone names are stored only once.
+    /// The empty string is always at index [`Database::EMPTY_STRING`].
     pub strings: Vec<String>,
-    /// All geographic entries in compressed format. Each entry contains indices into
-    /// the string table and fixed-point coordinates.
-    pub places: Vec<CompactPlace>,
+    /// Latitude column as fixed-point integers (multiply by 100,000 to get decimal degrees)
+    pub lats: Vec<i32>,
+    /// Longitude column as fixed-point integers (multiply by 100,000 to get decimal degrees)
+    pub lons: Vec<i32>,
+    /// City name column (string table indices)
+    pub cities: Vec<u32>,
+    /// ASCII city name column (string table indices)
+    pub cities_ascii: Vec<u32>,
+    /// Region name column (string table indices)
+    pub regions: Vec<u32>,
+    /// Region code column (string table indices)
+    pub region_codes: Vec<u32>,
+    /// District name column (string table indices)
+    pub districts: Vec<u32>,
+    /// Subdistrict name column (string table indices)
+    pub subdistricts: Vec<u32>,
+    /// Country code column (string table indices)
+    pub country_codes: Vec<u32>,
+    /// Postal code column (string table indices)
+    pub postal_codes: Vec<u32>,
+    /// Timezone identifier column (string table indices)
+    pub timezones: Vec<u32>,
+    /// GeoNames feature code column (string table indices)
+    pub feature_codes: Vec<u32>,
+    /// Modification date column in days since 1970-01-01 (0 if unknown)
+    pub modified: Vec<u16>,
+    /// Population column (0 if unknown)
+    pub populations: Vec<u32>,
+    /// Alternate name offsets: the localized names of place `i` are
+    /// `names[name_offsets[i]..name_offsets[i + 1]]`. May be empty, in which case no
+    /// place has alternate names.
+    pub name_offsets: Vec<u32>,
+    /// Concatenated `(language, name)` string table index pairs
+    pub names: Vec<(u32, u32)>,
     /// Spatial index mapping grid cells to place indices. The world is divided into
-    /// 0.1° × 0.1° cells (~11km at equator). Each cell contains a vector of indices
-    /// into the `places` vector.
+    /// 0.1° × 0.1° cells (~11km at equator). Each cell lists indices into the place
+    /// columns.
+    pub grid: Grid,
+}
+
+impl Database {
+    /// Magic bytes at the start of every database file.
+    pub const MAGIC: [u8; 4] = *b"GNOM";
+
+    /// Version of the binary layout written by the builder and read by
+    /// [`Geocoder::from_bytes()`](crate::Geocoder::from_bytes).
+    pub const FORMAT_VERSION: u32 = 2;
+
+    /// String table index of the empty string.
+    ///
+    /// The builder interns `""` first, so a missing value such as an absent postal code
+    /// can be detected by comparing the index instead of fetching the string. Databases
+    /// that break this rule (older files, hand-built or merged ones) are normalized by
+    /// [`Geocoder::from_database()`](crate::Geocoder::from_database).
+    pub const EMPTY_STRING: u32 = 0;
+
+    /// Returns the number of places in the database.
+    pub fn len(&self) -> usize {
+        self.lats.len()
+    }
+
+    /// Returns `true` if the database contains no places.
+    pub fn is_empty(&self) -> bool {
+        self.lats.is_empty()
+    }
+
+    /// Reassembles the place at `idx` from the columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn place(&self, idx: usize) -> CompactPlace {
+        CompactPlace {
+            city: self.cities[idx],
+            city_ascii: self.cities_ascii[idx],
+            region: self.regions[idx],
+            region_code: self.region_codes[idx],
+            district: self.districts[idx],
+            subdistrict: self.subdistricts[idx],
+            country_code: self.country_codes[idx],
+            postal_code: self.postal_codes[idx],
+            timezone: self.timezones[idx],
+            feature_code: self.feature_codes[idx],
+            modified: self.modified[idx],
+            population: self.populations[idx],
+            lat: self.lats[idx],
+            lon: self.lons[idx],
+        }
+    }
+
+    /// Returns the localized `(language, name)` index pairs of the place at `idx`.
+    pub fn names(&self, idx: usize) -> &[(u32, u32)] {
+        match (self.name_offsets.get(idx), self.name_offsets.get(idx + 1)) {
+            (Some(&start), Some(&end)) => &self.names[start as usize..end as usize],
+            _ => &[],
+        }
+    }
+
+    /// Returns the coordinates of the place at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn location(&self, idx: usize) -> Location {
+        Location {
+            latitude: self.lats[idx] as f64 / 100000.0,
+            longitude: self.lons[idx] as f64 / 100000.0,
+        }
+    }
+
+    /// Appends a place to the end of every column.
+    ///
+    /// The place gets no alternate names. The grid index is not updated; callers must
+    /// insert `self.len() - 1` into the appropriate cell themselves.
+    pub fn push(&mut self, place: CompactPlace) {
+        self.cities.push(place.city);
+        self.cities_ascii.push(place.city_ascii);
+        self.regions.push(place.region);
+        self.region_codes.push(place.region_code);
+        self.districts.push(place.district);
+        self.subdistricts.push(place.subdistrict);
+        self.country_codes.push(place.country_code);
+        self.postal_codes.push(place.postal_code);
+        self.timezones.push(place.timezone);
+        self.feature_codes.push(place.feature_code);
+        self.modified.push(place.modified);
+        self.populations.push(place.population);
+        self.lats.push(place.lat);
+        self.lons.push(place.lon);
+        if self.name_offsets.is_empty() {
+            self.name_offsets.push(0);
+        }
+        self.name_offsets.push(self.names.len() as u32);
+    }
+
+    /// Moves the empty string to index [`Database::EMPTY_STRING`] and points every
+    /// reference to an empty string there.
+    ///
+    /// Does nothing if the string table already starts with the only empty string.
+    pub(crate) fn reserve_empty_string(&mut self) {
+        let reserved = self.strings.first().is_some_and(String::is_empty);
+        if reserved && !self.strings[1..].iter().any(String::is_empty) {
+            return;
+        }
+
+        let shift = u32::from(!reserved);
+        let strings = &self.strings;
+        let remap = |idx: &mut u32| {
+            *idx = if strings.get(*idx as usize).is_some_and(String::is_empty) {
+                Self::EMPTY_STRING
+            } else {
+                *idx + shift
+            };
+        };
+        for column in [
+            &mut self.cities,
+            &mut self.cities_ascii,
+            &mut self.regions,
+            &mut self.region_codes,
+            &mut self.districts,
+            &mut self.subdistricts,
+            &mut self.country_codes,
+            &mut self.postal_codes,
+            &mut self.timezones,
+            &mut self.feature_codes,
+        ] {
+            column.iter_mut().for_each(remap);
+        }
+        for (lang, name) in &mut self.names {
+            remap(lang);
+            remap(name);
+        }
+        if !reserved {
+            self.strings.insert(0, String::new());
+        }
+    }
+
+    /// Appends every place of `other`, including its alternate names and grid cells.
+    ///
+    /// The string tables are concatenated without deduplication, so strings that both
+    /// databases contain are stored twice. The header fields keep the older of the two
+    /// values (ignoring unknown ones), so [`Database::info()`] reflects the stalest part.
+    pub fn append(&mut self, other: Database) {
+        let string_offset = self.strings.len() as u32;
+        let place_offset = self.len() as u32;
+        let remap = |column: &mut Vec<u32>, other: Vec<u32>| {
+            column.extend(other.into_iter().map(|idx| idx + string_offset));
+        };
+
+        let older = |a: u64, b: u64| match (a, b) {
+            (0, b) => b,
+            (a, 0) => a,
+            (a, b) => a.min(b),
+        };
+        self.built_at = older(self.built_at, other.built_at);
+        self.data_date = older(u64::from(self.data_date), u64::from(other.data_date)) as u16;
+
+        if self.name_offsets.is_empty() {
+            self.name_offsets = vec![0; self.len() + 1];
+        }
+        let name_base = self.names.len() as u32;
+        if other.name_offsets.is_empty() {
+            self.name_offsets
+                .extend(std::iter::repeat_n(name_base, other.len()));
+        } else {
+            self.name_offsets.extend(
+                other.name_offsets[1..]
+                    .iter()
+                    .map(|offset| offset + name_base),
+            );
+        }
+        self.names.extend(
+            other
+                .names
+                .into_iter()
+                .map(|(lang, name)| (lang + string_offset, name + string_offset)),
+        );
+
+        let mut cells: Vec<((i16, i16), Vec<u32>)> = self
+            .grid
+            .iter()
+            .map(|(key, indices)| (key, indices.to_vec()))
+            .collect();
+        cells.extend(
+            other.grid.iter().map(|(key, indices)| {
+                (key, indices.iter().map(|idx| idx + place_offset).collect())
+            }),
+        );
+        let mut merged: Vec<((i16, i16), Vec<u32>)> = Vec::with_capacity(cells.len());
+        cells.sort_by_key(|(key, _)| *key);
+        for (key, indices) in cells {
+            match merged.last_mut() {
+                Some((last, existing)) if *last == key => existing.extend(indices),
+                _ => merged.push((key, indices)),
+            }
+        }
+        self.grid = Grid::from_cells(merged);
+
+        self.strings.extend(other.strings);
+        self.lats.extend(other.lats);
+        self.lons.extend(other.lons);
+        remap(&mut self.cities, other.cities);
+        remap(&mut self.cities_ascii, other.cities_ascii);
+        remap(&mut self.regions, other.regions);
+        remap(&mut self.region_codes, other.region_codes);
+        remap(&mut self.districts, other.districts);
+        remap(&mut self.subdistricts, other.subdistricts);
+        remap(&mut self.country_codes, other.country_codes);
+        remap(&mut self.postal_codes, other.postal_codes);
+        remap(&mut self.timezones, other.timezones);
+        remap(&mut self.feature_codes, other.feature_codes);
+        self.modified.extend(other.modified);
+        self.populations.extend(other.populations);
+    }
+}
+
+/// Spatial grid index in compressed sparse row (CSR) layout.
+///
+/// Non-empty cells are stored as a sorted key array plus an `offsets`/`indices` pair:
+/// the place indices of the cell `keys[i]` are `indices[offsets[i]..offsets[i + 1]]`.
+/// Compared to a hash map of per-cell vectors, this needs three allocations instead of
+/// one per cell, which makes loading faster and avoids heap fragmentation. Lookups
+/// binary-search the key array.
+///
+/// # Examples
+///
+/// ```
+/// use genom::types::Grid;
+///
+/// let grid = Grid::from_cells(vec![((10, 20), vec![0, 2]), ((-5, 3), vec![1])]);
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid.get((10, 20)), Some(&[0, 2][..]));
+/// assert_eq!(grid.get((0, 0)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Grid {
+    /// Grid keys of all non-empty cells, sorted ascending
+    pub keys: Vec<(i16, i16)>,
+    /// Start offset of each cell's indices, with one trailing entry equal to `indices.len()`
+    pub offsets: Vec<u32>,
+    /// Place indices of all cells, concatenated in key order
+    pub indices: Vec<u32>,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            offsets: vec![0],
+            indices: Vec::new(),
+        }
+    }
+}
+
+impl Grid {
+    /// Builds a grid from `(key, place indices)` pairs.
+    ///
+    /// Cells are sorted by key. Empty cells are dropped.
+    pub fn from_cells(cells: impl IntoIterator<Item = ((i16, i16), Vec<u32>)>) -> Self {
+        let mut cells: Vec<_> = cells.into_iter().filter(|(_, v)| !v.is_empty()).collect();
+        cells.sort_unstable_by_key(|(key, _)| *key);
+
+        let mut grid = Grid {
+            keys: Vec::with_capacity(cells.len()),
+            offsets: Vec::with_capacity(cells.len() + 1),
+            indices: Vec::new(),
+        };
+        grid.offsets.push(0);
+        for (key, indices) in cells {
+            grid.keys.push(key);
+            grid.indices.extend(indices);
+            grid.offsets.push(grid.indices.len() as u32);
+        }
+        grid
+    }
+
+    /// Returns the place indices in the cell with the given key, if it is non-empty.
+    pub fn get(&self, key: (i16, i16)) -> Option<&[u32]> {
+        let i = self.keys.binary_search(&key).ok()?;
+        Some(self.cell(i))
+    }
+
+    /// Returns the number of non-empty cells.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterates over all cells as `(key, place indices)` pairs in key order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i16, i16), &[u32])> + '_ {
+        self.keys
+            .iter()
+            .enumerate()
+            .map(|(i, &key)| (key, self.cell(i)))
+    }
+
+    fn cell(&self, i: usize) -> &[u32] {
+        &self.indices[self.offsets[i] as usize..self.offsets[i + 1] as usize]
+    }
+}
+
+/// Summary statistics describing a loaded database.
+///
+/// Returned by [`Geocoder::stats()`](crate::Geocoder::stats). Useful for capacity planning
+/// and for verifying that a build contains the expected countries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseStats {
+    /// Number of places in the database
+    pub place_count: usize,
+    /// Number of distinct strings in the string table
+    pub string_count: usize,
+    /// Number of non-empty grid cells in the spatial index
+    pub grid_cell_count: usize,
+    /// Distinct ISO 3166-1 alpha-2 country codes present, sorted alphabetically
+    pub countries: Vec<String>,
+    /// Approximate memory allocated for the database's columns, strings, and grid, in
+    /// bytes. Hash map bookkeeping and allocator overhead are not included.
+    pub approx_memory_bytes: usize,
+}
+
+/// Build metadata recorded in the database header.
+///
+/// Returned by [`Geocoder::database_info()`](crate::Geocoder::database_info). Useful for
+/// tracking down stale results and for asserting in CI that a database is recent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseInfo {
+    /// Version of the binary layout
+    pub format_version: u32,
+    /// When the database was built, or `None` for databases built in memory
+    pub built_at: Option<DateTime<Utc>>,
+    /// Most recent GeoNames modification date of any place, approximating the date of
+    /// the GeoNames snapshot. `None` if no place has a modification date.
+    pub data_date: Option<NaiveDate>,
+    /// Number of places in the database
+    pub place_count: usize,
+}
+
+impl Database {
+    /// Returns the header metadata of the database.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::types::Database;
+    ///
+    /// let info = Database::default().info();
+    /// assert_eq!(info.format_version, Database::FORMAT_VERSION);
+    /// assert_eq!(info.built_at, None);
+    /// assert_eq!(info.place_count, 0);
+    /// ```
+    pub fn info(&self) -> DatabaseInfo {
+        DatabaseInfo::from_header(self.built_at, self.data_date, self.len())
+    }
+}
+
+impl DatabaseInfo {
+    /// Converts the raw header fields: seconds since the Unix epoch (0 if unknown) and
+    /// days since the Unix epoch (0 if unknown).
+    pub(crate) fn from_header(built_at: u64, data_date: u16, place_count: usize) -> Self {
+        Self {
+            format_version: Database::FORMAT_VERSION,
+            built_at: match built_at {
+                0 => None,
+                secs => i64::try_from(secs)
+                    .ok()
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0)),
+            },
+            data_date: date_from_days(data_date),
+            place_count,
+        }
+    }
+}
+
+/// Places that differ between two databases.
+///
+/// Returned by [`Database::diff()`] and [`Geocoder::diff()`](crate::Geocoder::diff).
+/// Indices refer to the place columns of the respective database and are sorted
+/// ascending, so they can be resolved with
+/// [`Geocoder::place_at()`](crate::Geocoder::place_at).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DatabaseDiff {
+    /// Places of the newer database without a counterpart in the older one
+    pub added: Vec<usize>,
+    /// Places of the older database without a counterpart in the newer one
+    pub removed: Vec<usize>,
+    /// Matched places whose coordinates shifted by more than the threshold, ordered by
+    /// their index in the older database
+    pub moved: Vec<MovedPlace>,
+}
+
+impl DatabaseDiff {
+    /// Returns `true` if no place was added, removed or moved.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+/// A place whose coordinates changed between two databases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovedPlace {
+    /// Index of the place in the older database
+    pub old_index: usize,
+    /// Index of the place in the newer database
+    pub new_index: usize,
+    /// Great-circle distance between the old and new coordinates in kilometers
+    pub distance_km: f64,
+}
+
+impl Database {
+    /// Compares this database with a newer one and reports added, removed and moved
+    /// places.
+    ///
+    /// Places are matched by country code and city name. Among places sharing both, the
+    /// closest old/new pairs are matched first, so same-named villages pair up with
+    /// their own counterparts. A matched place counts as moved if its coordinates
+    /// shifted by more than `threshold_km`, however far it went: a city that suddenly
+    /// jumps continents shows up with a distance of thousands of kilometers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genom::types::Database;
+    ///
+    /// let diff = Database::default().diff(&Database::default(), 1.0);
+    /// assert!(diff.is_empty());
+    /// ```
+    pub fn diff(&self, other: &Database, threshold_km: f64) -> DatabaseDiff {
+        let mut groups: FxHashMap<(&str, &str), [Vec<usize>; 2]> = FxHashMap::default();
+        for idx in 0..self.len() {
+            groups.entry(self.country_and_city(idx)).or_default()[0].push(idx);
+        }
+        for idx in 0..other.len() {
+            groups.entry(other.country_and_city(idx)).or_default()[1].push(idx);
+        }
+
+        let mut diff = DatabaseDiff::default();
+        for [old, new] in groups.into_values() {
+            // Pairs of positions in `old` and `new`, closest first
+            let mut pairs: Vec<(f64, usize, usize)> = Vec::with_capacity(old.len() * new.len());
+            for (i, &old_index) in old.iter().enumerate() {
+                let location = self.location(old_index);
+                for (j, &new_index) in new.iter().enumerate() {
+                    pairs.push((location.distance_to(&other.location(new_index)), i, j));
+                }
+            }
+            pairs.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+            let mut old_matched = vec![false; old.len()];
+            let mut new_matched = vec![false; new.len()];
+            for (distance_km, i, j) in pairs {
+                if old_matched[i] || new_matched[j] {
+                    continue;
+                }
+                old_matched[i] = true;
+                new_matched[j] = true;
+                if distance_km > threshold_km {
+                    diff.moved.push(MovedPlace {
+                        old_index: old[i],
+                        new_index: new[j],
+                        distance_km,
+                    });
+                }
+            }
+            let unmatched = |indices: &[usize], matched: &[bool]| {
+                indices
+                    .iter()
+                    .zip(matched)
+                    .filter(|(_, &matched)| !matched)
+                    .map(|(&idx, _)| idx)
+                    .collect::<Vec<_>>()
+            };
+            diff.removed.extend(unmatched(&old, &old_matched));
+            diff.added.extend(unmatched(&new, &new_matched));
+        }
+
+        diff.added.sort_unstable();
+        diff.removed.sort_unstable();
+        diff.moved.sort_unstable_by_key(|moved| moved.old_index);
+        diff
+    }
+
+    fn country_and_city(&self, idx: usize) -> (&str, &str) {
+        (
+            &self.strings[self.country_codes[idx] as usize],
+            &self.strings[self.cities[idx] as usize],
+        )
+    }
+}
+
+/// Statistics describing the nearest-place search of a single lookup.
+///
+/// Returned by [`Geocoder::lookup_debug()`](crate::Geocoder::lookup_debug) and
+/// [`Geocoder::lookup_hint_country_debug()`](crate::Geocoder::lookup_hint_country_debug).
+/// A high `candidates_checked` count points to dense grid cells (slow lookups); a query
+/// that checks no candidates at all fell into an empty neighborhood (a miss).
+///
+/// `rings_expanded` doubles as a confidence signal for searches that widen beyond the
+/// 3×3 neighborhood: a match found several rings out is far from the query and may
+/// warrant filtering. [`Geocoder::lookup_debug()`](crate::Geocoder::lookup_debug) never
+/// expands, so it always reports `0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LookupDebug {
+    /// Number of places whose distance to the query was computed
+    pub candidates_checked: usize,
+    /// Number of grid cells probed, including empty ones
+    pub grid_cells_searched: usize,
+    /// Number of rings searched beyond the initial 3×3 neighborhood (each ring adds
+    /// ~11 km of search radius)
+    pub rings_expanded: usize,
+    /// Distance to the nearest place in kilometers, if one was found
+    pub nearest_distance_km: Option<f64>,
+    /// Index of the nearest place in the database, if one was found. Resolve it again
+    /// later with [`Geocoder::place_at()`](crate::Geocoder::place_at).
+    pub place_index: Option<usize>,
+}
+
+/// Search settings for [`Geocoder::lookup_with_options()`](crate::Geocoder::lookup_with_options).
+///
+/// The default reproduces [`Geocoder::lookup()`](crate::Geocoder::lookup): the nearest
+/// place in the 3×3 cell neighborhood, with no filters. Each `with_` method adds one
+/// constraint, and constraints combine.
+///
+/// # Examples
+///
+/// ```
+/// use genom::LookupOptions;
+///
+/// let options = LookupOptions::default()
+///     .with_allowed_countries(&["FR", "BE"])
+///     .with_max_rings(5)
+///     .with_max_distance_km(25.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookupOptions {
+    pub(crate) max_distance_km: Option<f64>,
+    pub(crate) max_rings: u16,
+    pub(crate) allowed_countries: Vec<String>,
+    pub(crate) prefer_postal: bool,
+    pub(crate) feature_codes: Vec<String>,
+}
+
+impl Default for LookupOptions {
+    fn default() -> Self {
+        Self {
+            max_distance_km: None,
+            max_rings: 1,
+            allowed_countries: Vec::new(),
+            prefer_postal: false,
+            feature_codes: Vec::new(),
+        }
+    }
+}
+
+impl LookupOptions {
+    /// Rejects results farther than `max_distance_km` from the query.
+    pub fn with_max_distance_km(mut self, max_distance_km: f64) -> Self {
+        self.max_distance_km = Some(max_distance_km);
+        self
+    }
+
+    /// Searches up to `max_rings` rings of 0.1° cells around the query cell (~11 km per
+    /// ring at the equator). The default of 1 is the 3×3 neighborhood; 0 searches only
+    /// the query cell. Wider searches stop early once no unsearched cell can hold a
+    /// closer match. Limits above 1800 rings are clamped to 1800, which already covers
+    /// the whole grid. Ring `r` has `8r` cells, so a search that finds no match visits
+    /// about `4 × max_rings²` cells: well under a millisecond at 30 rings, a few hundred
+    /// milliseconds at the maximum.
+    ///
+    /// # Examples
+    ///
+    /// A match in ring `r` does not end the search, since a nearer place can sit in ring
+    /// `r + 1`. At 60° N a cell is half as wide as it is tall, so a place three cells east
+    /// of the query is nearer than one two cells north:
+    ///
+    /// ```
+    /// use genom::types::{CompactPlace, Database, Grid};
+    /// use genom::{Geocoder, LookupOptions};
+    ///
+    /// let mut db = Database::default();
+    /// db.strings = ["", "Near", "Far", "NO", "Europe/Oslo", "PPL"].map(String::from).to_vec();
+    /// let mut cells = Vec::new();
+    /// for (city, latitude, longitude) in [(1, 60.05, 0.35), (2, 60.25, 0.05)] {
+    ///     let (lat, lon) = ((latitude * 100000.0) as i32, (longitude * 100000.0) as i32);
+    ///     let place = CompactPlace {
+    ///         city, city_ascii: city, region: 0, region_code: 0, district: 0,
+    ///         subdistrict: 0, country_code: 3, postal_code: 0, timezone: 4,
+    ///         feature_code: 5, modified: 0, population: 0, lat, lon,
+    ///     };
+    ///     db.push(place);
+    ///     let key = ((lat / 10000) as i16, (lon / 10000) as i16);
+    ///     cells.push((key, vec![db.len() as u32 - 1]));
+    /// }
+    /// db.grid = Grid::from_cells(cells);
+    /// let geocoder = Geocoder::from_database(db);
     ///
-    /// Uses `FxHashMap` (from `rustc-hash`) for faster hashing
-    /// of integer keys compared to the standard library's `HashMap`.
-    pub grid: rustc_hash::FxHashMap<(i16, i16), Vec<u32>>,
+    /// // "Far" is in ring 2 (~22 km), "Near" in ring 3 (~17 km)
+    /// let options = LookupOptions::default().with_max_rings(10);
+    /// let place = geocoder.lookup_with_options(60.05, 0.05, &options).unwrap();
+    /// assert_eq!(place.city, "Near");
+    ///
+    /// // Stopping at the first ring with a match would have returned "Far"
+    /// let options = LookupOptions::default().with_max_rings(2);
+    /// let place = geocoder.lookup_with_options(60.05, 0.05, &options).unwrap();
+    /// assert_eq!(place.city, "Far");
+    /// ```
+    pub fn with_max_rings(mut self, max_rings: u16) -> Self {
+        self.max_rings = max_rings;
+        self
+    }
+
+    /// Only returns places in one of the given countries (ISO 3166-1 alpha-2 codes,
+    /// case-insensitive). An empty list allows every country.
+    pub fn with_allowed_countries(mut self, countries: &[&str]) -> Self {
+        self.allowed_countries = countries.iter().map(|c| c.trim().to_string()).collect();
+        self
+    }
+
+    /// Breaks near-ties (within 250 m) in favor of places with a postal code, like
+    /// [`Geocoder::lookup_prefer_postal()`](crate::Geocoder::lookup_prefer_postal).
+    pub fn with_prefer_postal(mut self, prefer_postal: bool) -> Self {
+        self.prefer_postal = prefer_postal;
+        self
+    }
+
+    /// Only returns places with one of the given GeoNames feature codes (e.g., `PPLC`,
+    /// case-insensitive), like
+    /// [`Geocoder::lookup_nearest_of_type()`](crate::Geocoder::lookup_nearest_of_type)
+    /// but without its whole-database fallback. An empty list allows every code.
+    pub fn with_feature_codes(mut self, codes: &[&str]) -> Self {
+        self.feature_codes = codes.iter().map(|c| c.trim().to_string()).collect();
+        self
+    }
+}
+
+/// An ocean or sea, returned for coordinates with no place nearby.
+///
+/// Produced by [`Geocoder::lookup_or_water()`](crate::Geocoder::lookup_or_water). Requires
+/// the `water` feature.
+#[cfg(feature = "water")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WaterBody {
+    /// English name of the ocean or sea (e.g., "North Atlantic Ocean", "Baltic Sea")
+    pub name: String,
+}
+
+/// The result of [`Geocoder::lookup_or_water()`](crate::Geocoder::lookup_or_water): the
+/// nearest place, or the water body for coordinates with no place nearby.
+///
+/// Requires the `water` feature.
+#[cfg(feature = "water")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::large_enum_variant)] // places are the common case; boxing would cost them an allocation
+pub enum LookupResult {
+    /// A place was found near the coordinates
+    Place(Place),
+    /// No place is nearby; the coordinates were classified as open water
+    Water(WaterBody),
+}
+
+#[cfg(feature = "water")]
+impl LookupResult {
+    /// Returns the place, if the lookup found one.
+    pub fn place(&self) -> Option<&Place> {
+        match self {
+            Self::Place(place) => Some(place),
+            Self::Water(_) => None,
+        }
+    }
+
+    /// Returns the display name: the place's city or the water body's name.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Place(place) => &place.city,
+            Self::Water(water) => &water.name,
+        }
+    }
 }
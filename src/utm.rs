@@ -0,0 +1,248 @@
+//! Universal Transverse Mercator projection and MGRS grid references.
+//!
+//! The projection uses the Krüger series to third order in the WGS84 third flattening,
+//! which is accurate to well under a millimeter within the extent of a UTM zone. MGRS
+//! references are derived from UTM with the AA lettering scheme used for WGS84.
+
+/// WGS84 semi-major axis in meters.
+const WGS84_A: f64 = 6378137.0;
+/// WGS84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257223563;
+/// Scale factor on the central meridian.
+const K0: f64 = 0.9996;
+/// False easting applied to every zone.
+const FALSE_EASTING: f64 = 500_000.0;
+/// False northing applied in the southern hemisphere.
+const FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
+/// MGRS latitude band letters from 80°S to 84°N, 8° each (X spans 12°).
+const MGRS_BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+/// 100 km column letters, cycling every three zones.
+const MGRS_COLUMNS: [&[u8; 8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+/// 100 km row letters, repeating every 2,000 km of northing.
+const MGRS_ROWS: &[u8; 20] = b"ABCDEFGHJKLMNPQRSTUV";
+/// Smallest northing (meters, modulo the false northing) inside each latitude band.
+const MGRS_BAND_MIN_NORTHING: [f64; 20] = [
+    1_100_000.0,
+    2_000_000.0,
+    2_800_000.0,
+    3_700_000.0,
+    4_600_000.0,
+    5_500_000.0,
+    6_400_000.0,
+    7_300_000.0,
+    8_200_000.0,
+    9_100_000.0,
+    0.0,
+    800_000.0,
+    1_700_000.0,
+    2_600_000.0,
+    3_500_000.0,
+    4_400_000.0,
+    5_300_000.0,
+    6_200_000.0,
+    7_000_000.0,
+    7_900_000.0,
+];
+
+/// Constants of the Krüger series derived from the WGS84 ellipsoid.
+struct Series {
+    /// Third flattening.
+    n: f64,
+    /// Rectifying radius.
+    a: f64,
+    alpha: [f64; 3],
+    beta: [f64; 3],
+    delta: [f64; 3],
+}
+
+impl Series {
+    fn wgs84() -> Self {
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let (n2, n3) = (n * n, n * n * n);
+        Self {
+            n,
+            a: WGS84_A / (1.0 + n) * (1.0 + n2 / 4.0 + n2 * n2 / 64.0),
+            alpha: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0,
+                13.0 * n2 / 48.0 - 3.0 * n3 / 5.0,
+                61.0 * n3 / 240.0,
+            ],
+            beta: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0,
+                n2 / 48.0 + n3 / 15.0,
+                17.0 * n3 / 480.0,
+            ],
+            delta: [
+                2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3,
+                7.0 * n2 / 3.0 - 8.0 * n3 / 5.0,
+                56.0 * n3 / 15.0,
+            ],
+        }
+    }
+}
+
+/// Returns the UTM zone for a coordinate, including the Norway and Svalbard exceptions.
+///
+/// Returns `None` outside the UTM latitude range of 80°S to 84°N.
+pub(crate) fn zone(latitude: f64, longitude: f64) -> Option<u8> {
+    if !(-80.0..=84.0).contains(&latitude) || !longitude.is_finite() {
+        return None;
+    }
+    let longitude = (longitude + 180.0).rem_euclid(360.0) - 180.0;
+
+    if (56.0..64.0).contains(&latitude) && (3.0..12.0).contains(&longitude) {
+        return Some(32);
+    }
+    if latitude >= 72.0 && (0.0..42.0).contains(&longitude) {
+        return Some(match longitude {
+            lon if lon < 9.0 => 31,
+            lon if lon < 21.0 => 33,
+            lon if lon < 33.0 => 35,
+            _ => 37,
+        });
+    }
+    Some((((longitude + 180.0) / 6.0) as u8).min(59) + 1)
+}
+
+fn central_meridian(zone: u8) -> f64 {
+    f64::from(zone) * 6.0 - 183.0
+}
+
+/// Projects a coordinate into the given zone, returning `(easting, northing)` in meters.
+///
+/// Southern-hemisphere northings include the 10,000 km false northing.
+pub(crate) fn project(latitude: f64, longitude: f64, zone: u8) -> (f64, f64) {
+    let series = Series::wgs84();
+    let phi = latitude.to_radians();
+    let lambda = (longitude - central_meridian(zone)).to_radians();
+
+    let c = 2.0 * series.n.sqrt() / (1.0 + series.n);
+    let t = (phi.sin().atanh() - c * (c * phi.sin()).atanh()).sinh();
+    let xi_prime = t.atan2(lambda.cos());
+    let eta_prime = (lambda.sin() / (1.0 + t * t).sqrt()).atanh();
+
+    let (mut xi, mut eta) = (xi_prime, eta_prime);
+    for (j, alpha) in series.alpha.iter().enumerate() {
+        let k = 2.0 * (j + 1) as f64;
+        xi += alpha * (k * xi_prime).sin() * (k * eta_prime).cosh();
+        eta += alpha * (k * xi_prime).cos() * (k * eta_prime).sinh();
+    }
+
+    let easting = FALSE_EASTING + K0 * series.a * eta;
+    let northing = K0 * series.a * xi;
+    if latitude < 0.0 {
+        (easting, northing + FALSE_NORTHING_SOUTH)
+    } else {
+        (easting, northing)
+    }
+}
+
+/// Inverts [`project`], returning `(latitude, longitude)` in degrees.
+pub(crate) fn unproject(zone: u8, north: bool, easting: f64, northing: f64) -> (f64, f64) {
+    let series = Series::wgs84();
+    let northing = if north {
+        northing
+    } else {
+        northing - FALSE_NORTHING_SOUTH
+    };
+    let xi = northing / (K0 * series.a);
+    let eta = (easting - FALSE_EASTING) / (K0 * series.a);
+
+    let (mut xi_prime, mut eta_prime) = (xi, eta);
+    for (j, beta) in series.beta.iter().enumerate() {
+        let k = 2.0 * (j + 1) as f64;
+        xi_prime -= beta * (k * xi).sin() * (k * eta).cosh();
+        eta_prime -= beta * (k * xi).cos() * (k * eta).sinh();
+    }
+
+    let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+    let mut phi = chi;
+    for (j, delta) in series.delta.iter().enumerate() {
+        phi += delta * (2.0 * (j + 1) as f64 * chi).sin();
+    }
+    let lambda = eta_prime.sinh().atan2(xi_prime.cos());
+
+    (
+        phi.to_degrees(),
+        (central_meridian(zone) + lambda.to_degrees() + 180.0).rem_euclid(360.0) - 180.0,
+    )
+}
+
+/// Formats a coordinate as an MGRS reference with `precision` digits per axis (0-5).
+pub(crate) fn to_mgrs(latitude: f64, longitude: f64, precision: u8) -> Option<String> {
+    let zone = zone(latitude, longitude)?;
+    let band = MGRS_BANDS[(((latitude + 80.0) / 8.0) as usize).min(19)] as char;
+    let (easting, northing) = project(latitude, longitude, zone);
+
+    let set = usize::from((zone - 1) % 3);
+    let column = *MGRS_COLUMNS[set].get(((easting / 100_000.0) as usize).checked_sub(1)?)? as char;
+    let row_offset = if zone.is_multiple_of(2) { 5 } else { 0 };
+    let row = MGRS_ROWS[((northing / 100_000.0) as usize + row_offset) % 20] as char;
+
+    let precision = u32::from(precision.min(5));
+    let scale = 10f64.powi(5 - precision as i32);
+    let digits = |value: f64| (value.rem_euclid(100_000.0) / scale) as u32;
+    let width = precision as usize;
+
+    let mut mgrs = format!("{}{}{}{}", zone, band, column, row);
+    if width > 0 {
+        mgrs.push_str(&format!(
+            "{:0width$}{:0width$}",
+            digits(easting),
+            digits(northing)
+        ));
+    }
+    Some(mgrs)
+}
+
+/// Parses an MGRS reference into the coordinate of the south-west corner of its square.
+pub(crate) fn from_mgrs(s: &str) -> Option<(f64, f64)> {
+    let s: Vec<u8> = s
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| b.to_ascii_uppercase())
+        .collect();
+
+    let zone_len = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    if !(1..=2).contains(&zone_len) || s.len() < zone_len + 3 {
+        return None;
+    }
+    let zone: u8 = std::str::from_utf8(&s[..zone_len]).ok()?.parse().ok()?;
+    if !(1..=60).contains(&zone) {
+        return None;
+    }
+
+    let band = MGRS_BANDS.iter().position(|&b| b == s[zone_len])?;
+    let set = usize::from((zone - 1) % 3);
+    let column = MGRS_COLUMNS[set]
+        .iter()
+        .position(|&b| b == s[zone_len + 1])?;
+    let row = MGRS_ROWS.iter().position(|&b| b == s[zone_len + 2])?;
+
+    let digits = &s[zone_len + 3..];
+    if !digits.len().is_multiple_of(2)
+        || digits.len() > 10
+        || !digits.iter().all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+    let precision = digits.len() / 2;
+    let scale = 10f64.powi(5 - precision as i32);
+    let parse = |d: &[u8]| -> f64 {
+        d.iter()
+            .fold(0.0, |acc, &b| acc * 10.0 + f64::from(b - b'0'))
+            * scale
+    };
+
+    let easting = (column + 1) as f64 * 100_000.0 + parse(&digits[..precision]);
+    let row_offset = if zone.is_multiple_of(2) { 5 } else { 0 };
+    let mut northing =
+        ((row + 20 - row_offset) % 20) as f64 * 100_000.0 + parse(&digits[precision..]);
+    while northing < MGRS_BAND_MIN_NORTHING[band] {
+        northing += 2_000_000.0;
+    }
+
+    let north = band >= 10;
+    Some(unproject(zone, north, easting, northing))
+}
@@ -0,0 +1,116 @@
+//! Coarse classification of coordinates into oceans and seas.
+//!
+//! Each water body is represented by one or more anchor points spread across its
+//! extent, and a coordinate is assigned to the water body of the nearest anchor by
+//! great-circle distance. This is a low-resolution Voronoi partition of the globe, not
+//! a coastline model: it names the right ocean or major sea for open water, but is only
+//! approximate near the boundary between two water bodies.
+
+use crate::types::Location;
+
+/// Anchor points as `(name, latitude, longitude)`. Large oceans have several anchors so
+/// their cells reach across the whole basin instead of ending halfway to the nearest sea.
+const WATER_BODIES: &[(&str, f64, f64)] = &[
+    ("Arctic Ocean", 88.0, 0.0),
+    ("Arctic Ocean", 80.0, 120.0),
+    ("Arctic Ocean", 80.0, -160.0),
+    ("Arctic Ocean", 82.0, -60.0),
+    ("North Atlantic Ocean", 45.0, -40.0),
+    ("North Atlantic Ocean", 30.0, -45.0),
+    ("North Atlantic Ocean", 30.0, -70.0),
+    ("North Atlantic Ocean", 38.0, -68.0),
+    ("North Atlantic Ocean", 15.0, -40.0),
+    ("North Atlantic Ocean", 35.0, -25.0),
+    ("North Atlantic Ocean", 50.0, -20.0),
+    ("North Atlantic Ocean", 20.0, -25.0),
+    ("North Atlantic Ocean", 5.0, -30.0),
+    ("South Atlantic Ocean", -10.0, -20.0),
+    ("South Atlantic Ocean", -25.0, -15.0),
+    ("South Atlantic Ocean", -40.0, -30.0),
+    ("South Atlantic Ocean", -30.0, 5.0),
+    ("South Atlantic Ocean", -45.0, -10.0),
+    ("South Atlantic Ocean", -15.0, -30.0),
+    ("South Atlantic Ocean", -12.0, 5.0),
+    ("North Pacific Ocean", 30.0, -150.0),
+    ("North Pacific Ocean", 40.0, -170.0),
+    ("North Pacific Ocean", 20.0, -130.0),
+    ("North Pacific Ocean", 15.0, 170.0),
+    ("North Pacific Ocean", 35.0, 160.0),
+    ("North Pacific Ocean", 45.0, -140.0),
+    ("North Pacific Ocean", 10.0, -150.0),
+    ("North Pacific Ocean", 30.0, -125.0),
+    ("North Pacific Ocean", 10.0, -100.0),
+    ("South Pacific Ocean", -20.0, -130.0),
+    ("South Pacific Ocean", -35.0, -110.0),
+    ("South Pacific Ocean", -15.0, -160.0),
+    ("South Pacific Ocean", -40.0, -150.0),
+    ("South Pacific Ocean", -10.0, -100.0),
+    ("South Pacific Ocean", -30.0, -85.0),
+    ("South Pacific Ocean", -25.0, 180.0),
+    ("Indian Ocean", -20.0, 80.0),
+    ("Indian Ocean", -10.0, 65.0),
+    ("Indian Ocean", -30.0, 100.0),
+    ("Indian Ocean", -35.0, 60.0),
+    ("Indian Ocean", -5.0, 90.0),
+    ("Indian Ocean", -25.0, 50.0),
+    ("Indian Ocean", -42.0, 115.0),
+    ("Southern Ocean", -65.0, 0.0),
+    ("Southern Ocean", -62.0, 90.0),
+    ("Southern Ocean", -65.0, 180.0),
+    ("Southern Ocean", -65.0, -90.0),
+    ("Southern Ocean", -60.0, -45.0),
+    ("Southern Ocean", -62.0, 140.0),
+    ("Southern Ocean", -60.0, 45.0),
+    ("Adriatic Sea", 43.0, 15.0),
+    ("Aegean Sea", 38.5, 25.0),
+    ("Andaman Sea", 10.0, 96.0),
+    ("Arabian Sea", 15.0, 65.0),
+    ("Arafura Sea", -10.0, 135.0),
+    ("Baltic Sea", 55.5, 16.0),
+    ("Baltic Sea", 58.5, 20.0),
+    ("Baltic Sea", 62.0, 20.0),
+    ("Barents Sea", 74.0, 40.0),
+    ("Bay of Bengal", 15.0, 88.0),
+    ("Bay of Biscay", 45.0, -4.0),
+    ("Bering Sea", 58.0, -175.0),
+    ("Black Sea", 43.4, 34.0),
+    ("Caribbean Sea", 15.0, -75.0),
+    ("Caspian Sea", 41.5, 50.5),
+    ("Caspian Sea", 45.0, 50.0),
+    ("Coral Sea", -18.0, 155.0),
+    ("East China Sea", 29.0, 125.0),
+    ("Greenland Sea", 75.0, -5.0),
+    ("Gulf of Aden", 12.5, 48.0),
+    ("Gulf of Alaska", 57.0, -145.0),
+    ("Gulf of Guinea", 2.0, 3.0),
+    ("Gulf of Mexico", 25.0, -90.0),
+    ("Hudson Bay", 60.0, -85.0),
+    ("Java Sea", -5.0, 111.0),
+    ("Kara Sea", 76.0, 75.0),
+    ("Labrador Sea", 58.0, -55.0),
+    ("Mediterranean Sea", 35.0, 18.0),
+    ("Mediterranean Sea", 38.0, 5.0),
+    ("Mediterranean Sea", 34.0, 28.0),
+    ("North Sea", 56.0, 3.0),
+    ("Norwegian Sea", 68.0, 5.0),
+    ("Persian Gulf", 27.0, 51.0),
+    ("Philippine Sea", 20.0, 135.0),
+    ("Red Sea", 20.0, 38.5),
+    ("Ross Sea", -75.0, -175.0),
+    ("Sea of Japan", 40.0, 135.0),
+    ("Sea of Okhotsk", 55.0, 150.0),
+    ("South China Sea", 13.0, 114.0),
+    ("Tasman Sea", -40.0, 160.0),
+    ("Weddell Sea", -72.0, -45.0),
+    ("Yellow Sea", 35.5, 123.0),
+];
+
+/// Returns the name of the ocean or sea whose anchor is nearest to `location`.
+pub(crate) fn nearest_water_body(location: &Location) -> &'static str {
+    WATER_BODIES
+        .iter()
+        .map(|&(name, lat, lon)| (name, location.distance_to(&Location::new(lat, lon))))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(name, _)| name)
+        .expect("water body table is not empty")
+}